@@ -13,6 +13,10 @@ pub trait ScalarMul: Semigroup {
 pub trait ScalarPow: Semiring {
     fn scalar_pow(&self, e: usize) -> Self;
 }
+// 平方根 (存在しなければ None)
+pub trait Sqrt: Sized {
+    fn sqrt(&self) -> Option<Self>;
+}
 
 trait_alias! {Semigroup = Add<Output = Self> + AddAssign + Sized}
 trait_alias! {Monoid = Semigroup + Zero}
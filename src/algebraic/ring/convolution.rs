@@ -0,0 +1,402 @@
+use crate::algebraic::Zero;
+use std::ops::{AddAssign, Mul, SubAssign};
+
+// 畳み込み (多項式の積の係数)
+pub trait Convolution: Sized {
+    fn convolution(lhs: &[Self], rhs: &[Self]) -> Vec<Self>;
+}
+
+// NTT friendly な素数 p = c * 2^k + 1 と原始根
+pub const NTT_PRIMES: [(u32, u32); 3] = [(998244353, 3), (167772161, 3), (469762049, 3)];
+
+const NAIVE_THRESHOLD: usize = 60;
+const KARATSUBA_THRESHOLD: usize = 32;
+// これより短い畳み込みは 3 素数 NTT より Karatsuba の方が速い
+pub const THREE_PRIMES_THRESHOLD: usize = 64;
+// 3 素数 NTT で復元できる範囲 P1 P2 P3
+pub const THREE_PRIMES_PRODUCT: u128 =
+    NTT_PRIMES[0].0 as u128 * NTT_PRIMES[1].0 as u128 * NTT_PRIMES[2].0 as u128;
+
+fn pow_mod(mut a: u64, mut e: u64, m: u64) -> u64 {
+    let mut result = 1 % m;
+    a %= m;
+    while e > 0 {
+        if e & 1 == 1 {
+            result = result * a % m;
+        }
+        a = a * a % m;
+        e >>= 1;
+    }
+    result
+}
+
+// 素数 p の最小の原始根
+pub fn primitive_root(p: u32) -> u32 {
+    if p == 2 {
+        return 1;
+    }
+    let mut divisors = Vec::new();
+    let mut n = p - 1;
+    let mut d = 2;
+    while d * d <= n {
+        if n.is_multiple_of(d) {
+            divisors.push(d);
+            while n.is_multiple_of(d) {
+                n /= d;
+            }
+        }
+        d += 1;
+    }
+    if n > 1 {
+        divisors.push(n);
+    }
+    (2..p)
+        .find(|&g| {
+            divisors
+                .iter()
+                .all(|&d| pow_mod(g as u64, ((p - 1) / d) as u64, p as u64) != 1)
+        })
+        .unwrap()
+}
+
+// 長さ n の NTT が可能か
+pub fn is_ntt_friendly(p: u32, n: usize) -> bool {
+    n.is_power_of_two() && (p - 1).trailing_zeros() >= n.trailing_zeros()
+}
+
+// 数論変換 (a.len() は 2 冪)
+pub fn ntt(a: &mut [u32], p: u32, g: u32, invert: bool) {
+    let n = a.len();
+    assert!(
+        is_ntt_friendly(p, n),
+        "NTT of length {} is not supported mod {}",
+        n,
+        p
+    );
+    let m = p as u64;
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let mut w = pow_mod(g as u64, (m - 1) / len as u64, m);
+        if invert {
+            w = pow_mod(w, m - 2, m);
+        }
+        let mut ws = Vec::with_capacity(half);
+        ws.push(1u64);
+        for k in 1..half {
+            ws.push(ws[k - 1] * w % m);
+        }
+        for chunk in a.chunks_mut(len) {
+            let (lo, hi) = chunk.split_at_mut(half);
+            for k in 0..half {
+                let u = lo[k] as u64;
+                let v = hi[k] as u64 * ws[k] % m;
+                lo[k] = if u + v >= m { u + v - m } else { u + v } as u32;
+                hi[k] = if u >= v { u - v } else { u + m - v } as u32;
+            }
+        }
+        len <<= 1;
+    }
+
+    if invert {
+        let n_inv = pow_mod(n as u64, m - 2, m);
+        for x in a.iter_mut() {
+            *x = (*x as u64 * n_inv % m) as u32;
+        }
+    }
+}
+
+// NTT friendly な素数 p を法とする畳み込み
+pub fn convolution_ntt(lhs: &[u32], rhs: &[u32], p: u32) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let m = p as u64;
+    let len = lhs.len() + rhs.len() - 1;
+    if lhs.len().min(rhs.len()) <= NAIVE_THRESHOLD {
+        let mut res = vec![0u64; len];
+        for (i, &x) in lhs.iter().enumerate() {
+            for (j, &y) in rhs.iter().enumerate() {
+                res[i + j] = (res[i + j] + x as u64 * y as u64) % m;
+            }
+        }
+        return res.into_iter().map(|x| x as u32).collect();
+    }
+
+    let n = len.next_power_of_two();
+    let g = primitive_root(p);
+    let mut a = lhs.iter().map(|&x| x % p).collect::<Vec<_>>();
+    let mut b = rhs.iter().map(|&x| x % p).collect::<Vec<_>>();
+    a.resize(n, 0);
+    b.resize(n, 0);
+    ntt(&mut a, p, g, false);
+    ntt(&mut b, p, g, false);
+    for (x, &y) in a.iter_mut().zip(b.iter()) {
+        *x = (*x as u64 * y as u64 % m) as u32;
+    }
+    ntt(&mut a, p, g, true);
+    a.truncate(len);
+    a
+}
+
+// 各 NTT 素数での剰余から畳み込み、Garner で P1 P2 P3 を法とする値に復元する
+fn convolution_three_primes(residues: impl Fn(u32) -> (Vec<u32>, Vec<u32>)) -> Vec<u128> {
+    let [(m1, _), (m2, _), (m3, _)] = NTT_PRIMES;
+    let conv = |p: u32| {
+        let (lhs, rhs) = residues(p);
+        convolution_ntt(&lhs, &rhs, p)
+    };
+    let (r1, r2, r3) = (conv(m1), conv(m2), conv(m3));
+
+    let (m1, m2, m3) = (m1 as u64, m2 as u64, m3 as u64);
+    let m1_inv = pow_mod(m1, m2 - 2, m2);
+    let m1m2_inv = pow_mod(m1 * m2 % m3, m3 - 2, m3);
+    let m1m2 = m1 as u128 * m2 as u128;
+    r1.into_iter()
+        .zip(r2)
+        .zip(r3)
+        .map(|((r1, r2), r3)| {
+            let (r1, r2, r3) = (r1 as u64, r2 as u64, r3 as u64);
+            let t2 = (r2 + m2 - r1 % m2) % m2 * m1_inv % m2;
+            let t3 = (r3 + m3 - (r1 + m1 * t2) % m3) % m3 * m1m2_inv % m3;
+            r1 as u128 + m1 as u128 * t2 as u128 + m1m2 * t3 as u128
+        })
+        .collect()
+}
+
+// 任意の法での畳み込み (min(n, m) * modulus^2 < P1 P2 P3 の範囲で正確)
+pub fn convolution_mod(lhs: &[u32], rhs: &[u32], modulus: u32) -> Vec<u32> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    if let Some(&(p, _)) = NTT_PRIMES.iter().find(|&&(p, _)| p == modulus) {
+        return convolution_ntt(lhs, rhs, p);
+    }
+    let m = modulus as u64;
+    debug_assert!((m as u128).pow(2) * (lhs.len().min(rhs.len()) as u128) < THREE_PRIMES_PRODUCT);
+    let reduce = |v: &[u32], p: u32| -> Vec<u32> {
+        v.iter()
+            .map(|&x| (x as u64 % m % p as u64) as u32)
+            .collect()
+    };
+    convolution_three_primes(|p| (reduce(lhs, p), reduce(rhs, p)))
+        .into_iter()
+        .map(|x| (x % m as u128) as u32)
+        .collect()
+}
+
+// 整数係数の畳み込み (各係数の絶対値が P1 P2 P3 / 2 未満の範囲で正確)
+pub fn convolution_int(lhs: &[i64], rhs: &[i64]) -> Vec<i128> {
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    let reduce = |v: &[i64], p: u32| -> Vec<u32> {
+        v.iter().map(|&x| x.rem_euclid(p as i64) as u32).collect()
+    };
+    convolution_three_primes(|p| (reduce(lhs, p), reduce(rhs, p)))
+        .into_iter()
+        .map(|x| {
+            if x > THREE_PRIMES_PRODUCT / 2 {
+                x as i128 - THREE_PRIMES_PRODUCT as i128
+            } else {
+                x as i128
+            }
+        })
+        .collect()
+}
+
+fn convolution_naive<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    let mut res = vec![T::zero(); lhs.len() + rhs.len() - 1];
+    for (i, x) in lhs.iter().enumerate() {
+        for (j, y) in rhs.iter().enumerate() {
+            res[i + j] += x.clone() * y.clone();
+        }
+    }
+    res
+}
+
+// 任意の可換環上の Karatsuba 法
+pub fn karatsuba<T>(lhs: &[T], rhs: &[T]) -> Vec<T>
+where
+    T: Clone + Zero + AddAssign + SubAssign + Mul<Output = T>,
+{
+    if lhs.is_empty() || rhs.is_empty() {
+        return vec![];
+    }
+    if lhs.len().min(rhs.len()) <= KARATSUBA_THRESHOLD {
+        return convolution_naive(lhs, rhs);
+    }
+
+    let len = lhs.len() + rhs.len() - 1;
+    let k = lhs.len().max(rhs.len()) / 2;
+    let mut res = vec![T::zero(); len];
+    let add_shifted = |res: &mut Vec<T>, part: Vec<T>, shift: usize| {
+        for (i, x) in part.into_iter().enumerate() {
+            if i + shift < len {
+                res[i + shift] += x;
+            }
+        }
+    };
+
+    // 片方が短いときは長い方だけを分割する
+    if lhs.len() <= k || rhs.len() <= k {
+        let (long, short) = if lhs.len() <= k {
+            (rhs, lhs)
+        } else {
+            (lhs, rhs)
+        };
+        let (l0, l1) = long.split_at(k);
+        add_shifted(&mut res, karatsuba(l0, short), 0);
+        add_shifted(&mut res, karatsuba(l1, short), k);
+        return res;
+    }
+
+    let (a0, a1) = lhs.split_at(k);
+    let (b0, b1) = rhs.split_at(k);
+    let sum = |lo: &[T], hi: &[T]| {
+        let mut s = lo.to_vec();
+        s.resize(lo.len().max(hi.len()), T::zero());
+        for (i, x) in hi.iter().enumerate() {
+            s[i] += x.clone();
+        }
+        s
+    };
+    let z0 = karatsuba(a0, b0);
+    let z2 = karatsuba(a1, b1);
+    let mut z1 = karatsuba(&sum(a0, a1), &sum(b0, b1));
+    for (i, x) in z0.iter().enumerate() {
+        z1[i] -= x.clone();
+    }
+    for (i, x) in z2.iter().enumerate() {
+        z1[i] -= x.clone();
+    }
+    add_shifted(&mut res, z0, 0);
+    add_shifted(&mut res, z1, k);
+    add_shifted(&mut res, z2, 2 * k);
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::ZZ;
+    use crate::algebraic::ring::integer_mod::IntegerModRing;
+    use crate::algebraic::ring::polynomial::Polynomial;
+    use rand::Rng;
+
+    fn naive_mod(a: &[u32], b: &[u32], p: u32) -> Vec<u32> {
+        let mut res = vec![0u64; a.len() + b.len() - 1];
+        for (i, &x) in a.iter().enumerate() {
+            for (j, &y) in b.iter().enumerate() {
+                res[i + j] = (res[i + j] + x as u64 * y as u64) % p as u64;
+            }
+        }
+        res.into_iter().map(|x| x as u32).collect()
+    }
+
+    #[test]
+    fn primitive_roots() {
+        for (p, g) in NTT_PRIMES {
+            assert_eq!(primitive_root(p), g);
+        }
+        assert_eq!(primitive_root(7), 3);
+    }
+
+    #[test]
+    fn ntt_random() {
+        let mut rng = rand::thread_rng();
+        for (p, _) in NTT_PRIMES {
+            for (n, m) in [(1, 1), (3, 70), (100, 100), (257, 1000)] {
+                let a: Vec<u32> = (0..n).map(|_| rng.gen_range(0..p)).collect();
+                let b: Vec<u32> = (0..m).map(|_| rng.gen_range(0..p)).collect();
+                assert_eq!(convolution_ntt(&a, &b, p), naive_mod(&a, &b, p));
+            }
+        }
+    }
+
+    #[test]
+    fn karatsuba_random() {
+        let mut rng = rand::thread_rng();
+        for (n, m) in [(1, 5), (33, 33), (100, 7), (150, 300), (513, 511)] {
+            let a: Vec<i64> = (0..n).map(|_| rng.gen_range(-1000..1000)).collect();
+            let b: Vec<i64> = (0..m).map(|_| rng.gen_range(-1000..1000)).collect();
+            assert_eq!(karatsuba(&a, &b), convolution_naive(&a, &b));
+        }
+    }
+
+    #[test]
+    fn three_primes_random() {
+        let mut rng = rand::thread_rng();
+        for p in [1_000_000_007u32, 1 << 31, u32::MAX] {
+            let a: Vec<u32> = (0..300).map(|_| rng.gen_range(0..=u32::MAX)).collect();
+            let b: Vec<u32> = (0..500).map(|_| rng.gen_range(0..=u32::MAX)).collect();
+            let reduce = |v: &[u32]| v.iter().map(|&x| x % p).collect::<Vec<_>>();
+            assert_eq!(
+                convolution_mod(&a, &b, p),
+                naive_mod(&reduce(&a), &reduce(&b), p)
+            );
+        }
+
+        let a: Vec<i64> = (0..300).map(|_| rng.gen_range(-1 << 40..1 << 40)).collect();
+        let b: Vec<i64> = (0..500).map(|_| rng.gen_range(-1 << 40..1 << 40)).collect();
+        let a128 = a.iter().map(|&x| x as i128).collect::<Vec<_>>();
+        let b128 = b.iter().map(|&x| x as i128).collect::<Vec<_>>();
+        assert_eq!(convolution_int(&a, &b), convolution_naive(&a128, &b128));
+    }
+
+    #[test]
+    fn fps_mul_integer() {
+        let mut rng = rand::thread_rng();
+        // 3 素数 NTT で復元できる大きさ
+        let a: Vec<ZZ> = (0..200)
+            .map(|_| rng.gen_range(-1000..1000).into())
+            .collect();
+        let b: Vec<ZZ> = (0..300)
+            .map(|_| rng.gen_range(-1000..1000).into())
+            .collect();
+        assert_eq!(
+            (Polynomial::from(a.clone()) * Polynomial::from(b.clone())).coeff(),
+            convolution_naive(&a, &b)
+        );
+
+        // 大きすぎるので Karatsuba に落ちる
+        let big = ZZ::from(1) << 100;
+        let a: Vec<ZZ> = (0..200).map(|i| &big + i).collect();
+        let b: Vec<ZZ> = (0..300).map(|i| &big - i).collect();
+        assert_eq!(
+            (Polynomial::from(a.clone()) * Polynomial::from(b.clone())).coeff(),
+            convolution_naive(&a, &b)
+        );
+    }
+
+    #[test]
+    fn fps_mul_integer_mod() {
+        let mut rng = rand::thread_rng();
+        for p in [998244353u32, 1_000_000_007, 1 << 31] {
+            let ring = IntegerModRing::new(p.into());
+            let a: Vec<u32> = (0..200).map(|_| rng.gen_range(1..p)).collect();
+            let b: Vec<u32> = (0..300).map(|_| rng.gen_range(1..p)).collect();
+            let expected = naive_mod(&a, &b, p);
+            let elems = |v: &[u32]| v.iter().map(|&x| ring.elem(x.into())).collect::<Vec<_>>();
+            let f = Polynomial::from(elems(&a)) * Polynomial::from(elems(&b));
+            assert_eq!(f.coeff(), elems(&expected));
+        }
+    }
+}
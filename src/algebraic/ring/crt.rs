@@ -0,0 +1,265 @@
+use super::integer::ZZ;
+use super::integer_mod::{IntegerMod, IntegerModRing};
+use super::EuclidDomain;
+use crate::algebraic::Zero;
+use num::traits::NumAssign;
+use num::{Integer, Signed};
+
+// 正規化した剰余 (0 <= r < m)
+fn normalize<T: NumAssign + Signed + Clone>(r: T, m: &T) -> T {
+    let r = r % m.clone();
+    if r.is_negative() {
+        r + m.clone()
+    } else {
+        r
+    }
+}
+
+// 0 <= a, b < m でのあふれない a + b (mod m)
+fn add_mod<T: NumAssign + Signed + Clone + PartialOrd>(a: T, b: T, m: &T) -> T {
+    let rest = m.clone() - b.clone();
+    if a >= rest {
+        a - rest
+    } else {
+        a + b
+    }
+}
+
+// 0 <= a, b < m でのあふれない a b (mod m)
+pub trait MulMod: Sized {
+    fn mul_mod(a: Self, b: Self, m: &Self) -> Self;
+}
+
+impl MulMod for ZZ {
+    fn mul_mod(a: Self, b: Self, m: &Self) -> Self {
+        a * b % m
+    }
+}
+
+// 64bit 以下は i128 で掛ける
+macro_rules! impl_mul_mod {
+    ($($t:ty)*) => {$(
+        impl MulMod for $t {
+            fn mul_mod(a: Self, b: Self, m: &Self) -> Self {
+                (a as i128 * b as i128 % *m as i128) as $t
+            }
+        }
+    )*};
+}
+impl_mul_mod!(i8 i16 i32 i64 isize);
+
+// i128 は倍加法
+impl MulMod for i128 {
+    fn mul_mod(mut a: Self, mut b: Self, m: &Self) -> Self {
+        let mut res = 0;
+        while b != 0 {
+            if b & 1 == 1 {
+                res = add_mod(res, a, m);
+            }
+            a = add_mod(a, a, m);
+            b >>= 1;
+        }
+        res
+    }
+}
+
+// x ≡ r_i (mod m_i) を満たす x を (x, lcm m_i) の形で返す
+// 法は正で互いに素でなくてよく, 矛盾していれば None
+pub fn crt<T>(congruences: &[(T, T)]) -> Option<(T, T)>
+where
+    T: EuclidDomain + MulMod + NumAssign + Signed + Clone + PartialOrd,
+{
+    let (mut r, mut m) = (T::zero(), T::one());
+    for (b, n) in congruences {
+        assert!(n.is_positive(), "moduli must be positive");
+        let (d, x, _) = T::xgcd(m.clone(), n.clone());
+        let diff = normalize(b.clone(), n) - r.clone();
+        if !(diff.clone() % d.clone()).is_zero() {
+            return None;
+        }
+        // m x ≡ d (mod n) より t = (b - r) / d * x (mod n / d)
+        // (積があふれないように先に n / d で割った余りにしておく)
+        let n_d = n.clone() / d.clone();
+        let t = T::mul_mod(normalize(diff / d, &n_d), normalize(x, &n_d), &n_d);
+        // r < m, t < n / d なので r + m t < lcm
+        let lcm = m.clone() * n_d;
+        r = add_mod(r, T::mul_mod(m, t, &lcm), &lcm);
+        m = lcm;
+    }
+    Some((r, m))
+}
+
+// 互いに素な法での連立合同式の解を, bignum を使わずに modulus で割った余りで求める (Garner)
+// 法が互いに素でなければ None
+pub fn garner(congruences: &[(u64, u64)], modulus: u64) -> Option<u64> {
+    let mul = |a: u64, b: u64, m: u64| (a as u128 * b as u128 % m as u128) as u64;
+    let add = |a: u64, b: u64, m: u64| ((a as u128 + b as u128) % m as u128) as u64;
+    let inverse = |a: u64, m: u64| -> Option<u64> {
+        let (mut a, mut b) = (a as i128, m as i128);
+        let (mut x, mut y) = (1i128, 0i128);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        (a == 1).then(|| x.rem_euclid(m as i128) as u64)
+    };
+    assert!(modulus > 0, "modulus must be positive");
+    // coeffs[k] = m_0 ... m_{i-1} (mod m_k), constants[k] = ここまでの解 (mod m_k)
+    // (最後の要素は modulus 用)
+    let mut moduli: Vec<u64> = congruences.iter().map(|&(_, m)| m).collect();
+    moduli.push(modulus);
+    let mut coeffs: Vec<u64> = moduli.iter().map(|&m| 1 % m).collect();
+    let mut constants = vec![0u64; moduli.len()];
+    for (i, &(r, m)) in congruences.iter().enumerate() {
+        let t = mul(add(r % m, m - constants[i], m), inverse(coeffs[i], m)?, m);
+        for k in i + 1..moduli.len() {
+            let mk = moduli[k];
+            constants[k] = add(constants[k], mul(t, coeffs[k], mk), mk);
+            coeffs[k] = mul(coeffs[k], m, mk);
+        }
+    }
+    Some(constants[congruences.len()])
+}
+
+impl IntegerMod {
+    // self と other を同時に満たす元 (法は lcm)
+    pub fn crt(&self, other: &Self) -> Option<Self> {
+        Self::crt_list(&[self.clone(), other.clone()])
+    }
+
+    // 剰余の列を 1 つの元にまとめる (法は lcm, 法 0 の元はその整数そのものを表す)
+    pub fn crt_list(values: &[Self]) -> Option<Self> {
+        let (exact, congruences): (Vec<_>, Vec<_>) =
+            values.iter().partition(|x| x.modulus().is_zero());
+        let congruences: Vec<(ZZ, ZZ)> = congruences
+            .iter()
+            .map(|x| (x.value(), x.modulus()))
+            .collect();
+        let (r, m) = crt(&congruences)?;
+        match exact.first() {
+            None => Some(IntegerModRing::new(m).elem(r)),
+            Some(x) => {
+                let v = x.value();
+                let consistent =
+                    exact.iter().all(|y| y.value() == v) && (&v - &r).is_multiple_of(&m);
+                consistent.then(|| IntegerModRing::new(ZZ::zero()).elem(v))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::One;
+    use rand::Rng;
+
+    fn brute_force(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+        let l = congruences.iter().fold(1, |l, &(_, m)| l.lcm(&m));
+        (0..l)
+            .find(|x| congruences.iter().all(|&(r, m)| (x - r).rem_euclid(m) == 0))
+            .map(|x| (x, l))
+    }
+
+    #[test]
+    fn small() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..2000 {
+            let k = rng.gen_range(0..4);
+            let congruences: Vec<(i64, i64)> = (0..k)
+                .map(|_| (rng.gen_range(-50..50), rng.gen_range(1..30)))
+                .collect();
+            assert_eq!(
+                crt(&congruences),
+                brute_force(&congruences),
+                "{:?}",
+                congruences
+            );
+            let zz: Vec<(ZZ, ZZ)> = congruences
+                .iter()
+                .map(|&(r, m)| (r.into(), m.into()))
+                .collect();
+            assert_eq!(
+                crt(&zz),
+                brute_force(&congruences).map(|(r, m)| (r.into(), m.into()))
+            );
+        }
+        assert_eq!(crt(&[(2, 4), (1, 6)]), None);
+        assert_eq!(crt(&[(3, 4), (1, 6)]), Some((7, 12)));
+        // 法が 3 × 10^9 を超えても途中の積があふれない
+        assert_eq!(
+            crt::<i64>(&[(1, 2), (5_000_000_000, 5_000_000_001)]),
+            Some((10_000_000_001, 10_000_000_002))
+        );
+        for _ in 0..100 {
+            let congruences: Vec<(i64, i64)> = (0..2)
+                .map(|_| (rng.gen(), rng.gen_range(3_000_000_000..3_037_000_000)))
+                .collect();
+            let zz: Vec<(ZZ, ZZ)> = congruences
+                .iter()
+                .map(|&(r, m)| (r.into(), m.into()))
+                .collect();
+            assert_eq!(
+                crt(&congruences).map(|(r, m)| (ZZ::from(r), ZZ::from(m))),
+                crt(&zz)
+            );
+            // i128 では積が 2^128 を超える法でも倍加法であふれない
+            let congruences: Vec<(i128, i128)> = (0..2)
+                .map(|_| (rng.gen(), rng.gen_range(1 << 62..1 << 63)))
+                .collect();
+            let zz: Vec<(ZZ, ZZ)> = congruences
+                .iter()
+                .map(|&(r, m)| (r.into(), m.into()))
+                .collect();
+            assert_eq!(
+                crt(&congruences).map(|(r, m)| (ZZ::from(r), ZZ::from(m))),
+                crt(&zz)
+            );
+        }
+    }
+
+    #[test]
+    fn garner_mod() {
+        let mut rng = rand::thread_rng();
+        let primes = [998244353u64, 1_000_000_007, 1_000_000_009, (1 << 61) - 1];
+        for _ in 0..1000 {
+            // x は法の積 (約 2^150) 未満
+            let x = ZZ::from(rng.gen::<u128>()) * ZZ::from(rng.gen::<u16>());
+            let congruences: Vec<(u64, u64)> = primes
+                .iter()
+                .map(|&p| (u64::try_from(&x % p).unwrap(), p))
+                .collect();
+            for modulus in [1u64, 2, 998244353, u64::MAX] {
+                assert_eq!(
+                    garner(&congruences, modulus),
+                    Some(u64::try_from(&x % modulus).unwrap())
+                );
+            }
+        }
+        assert_eq!(garner(&[(1, 4), (1, 6)], 100), None);
+        assert_eq!(garner(&[], 100), Some(0));
+    }
+
+    #[test]
+    fn integer_mod_list() {
+        let a = IntegerModRing::new(4.into()).elem(3.into());
+        let b = IntegerModRing::new(6.into()).elem(1.into());
+        let c = a.crt(&b).unwrap();
+        assert_eq!(c.modulus(), 12.into());
+        assert_eq!(c.value(), 7.into());
+        let d = IntegerModRing::new(6.into()).elem(2.into());
+        assert_eq!(a.crt(&d), None);
+        let e = IntegerModRing::new(5.into()).elem(2.into());
+        let f = IntegerMod::crt_list(&[a.clone(), b, e]).unwrap();
+        assert_eq!((f.value(), f.modulus()), (7.into(), 60.into()));
+        let g = IntegerModRing::new(0.into()).elem(67.into());
+        assert_eq!(IntegerMod::crt_list(&[f.clone(), g.clone()]), Some(g));
+        let h = IntegerModRing::new(0.into()).elem(68.into());
+        assert_eq!(IntegerMod::crt_list(&[f, h]), None);
+        assert_eq!(
+            IntegerMod::crt_list(&[]).map(|x| x.modulus()),
+            Some(ZZ::one())
+        );
+    }
+}
@@ -0,0 +1,347 @@
+use super::sieve::LinearSieve;
+use super::ZZ;
+use crate::algebraic::ring::{Factor, UFD};
+use crate::algebraic::{One, Ring, Semiring};
+use num::Signed;
+use std::ops::Div;
+
+// 数論的関数 (n >= 1 を仮定する)
+pub trait EulerPhi {
+    fn euler_phi(self) -> Self;
+}
+pub trait Moebius {
+    fn moebius(self) -> i32;
+}
+pub trait Liouville {
+    fn liouville(self) -> i32;
+}
+pub trait DivisorSigma {
+    // 約数の k 乗和
+    fn divisor_sigma(self, k: u32) -> Self;
+    fn number_of_divisors(self) -> Self;
+}
+
+fn positive_factors(n: ZZ) -> Vec<Factor<ZZ>> {
+    assert!(
+        n.is_positive(),
+        "arithmetic functions are defined for n >= 1"
+    );
+    n.factors()
+}
+
+impl EulerPhi for ZZ {
+    fn euler_phi(self) -> Self {
+        positive_factors(self)
+            .into_iter()
+            .map(|Factor { p, e }| p.pow(e as u32 - 1) * (p - 1))
+            .product()
+    }
+}
+
+impl Moebius for ZZ {
+    fn moebius(self) -> i32 {
+        let factors = positive_factors(self);
+        if factors.iter().any(|f| f.e > 1) {
+            0
+        } else if factors.len().is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl Liouville for ZZ {
+    fn liouville(self) -> i32 {
+        let omega: usize = positive_factors(self).iter().map(|f| f.e).sum();
+        if omega.is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+}
+
+impl DivisorSigma for ZZ {
+    fn divisor_sigma(self, k: u32) -> Self {
+        positive_factors(self)
+            .into_iter()
+            .map(|Factor { p, e }| {
+                let pk = p.pow(k);
+                // 1 + p^k + ... + p^(ek)
+                (0..e).fold(ZZ::one(), |acc, _| acc * &pk + 1)
+            })
+            .product()
+    }
+
+    fn number_of_divisors(self) -> Self {
+        positive_factors(self)
+            .into_iter()
+            .map(|f| ZZ::from(f.e + 1))
+            .product()
+    }
+}
+
+macro_rules! impl_arithmetic_functions {
+    ($($t:ty)*) => {$(
+        impl EulerPhi for $t {
+            fn euler_phi(self) -> Self {
+                <$t>::try_from(ZZ::from(self).euler_phi()).unwrap()
+            }
+        }
+        impl Moebius for $t {
+            fn moebius(self) -> i32 {
+                ZZ::from(self).moebius()
+            }
+        }
+        impl Liouville for $t {
+            fn liouville(self) -> i32 {
+                ZZ::from(self).liouville()
+            }
+        }
+        impl DivisorSigma for $t {
+            fn divisor_sigma(self, k: u32) -> Self {
+                <$t>::try_from(ZZ::from(self).divisor_sigma(k)).unwrap()
+            }
+            fn number_of_divisors(self) -> Self {
+                <$t>::try_from(ZZ::from(self).number_of_divisors()).unwrap()
+            }
+        }
+    )*};
+}
+impl_arithmetic_functions!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// 乗法的関数の表 (f(p, e) = f(p^e) から線形篩で計算し, 添字 0 は 0 とする)
+pub fn multiplicative_table<T: Semiring + Clone>(
+    sieve: &LinearSieve,
+    f: impl Fn(usize, usize) -> T,
+) -> Vec<T> {
+    let n = sieve.limit();
+    let mut table = vec![T::zero(); n + 1];
+    // low[i] は i の最小素因数 p の冪部分 p^e, exp[i] はその e
+    let mut low = vec![1; n + 1];
+    let mut exp = vec![0; n + 1];
+    if n >= 1 {
+        table[1] = T::one();
+    }
+    for i in 2..=n {
+        let p = sieve.smallest_prime_factor(i);
+        let m = i / p;
+        if m.is_multiple_of(p) {
+            (low[i], exp[i]) = (low[m] * p, exp[m] + 1);
+        } else {
+            (low[i], exp[i]) = (p, 1);
+        }
+        table[i] = if low[i] == i {
+            f(p, exp[i])
+        } else {
+            table[i / low[i]].clone() * table[low[i]].clone()
+        };
+    }
+    table
+}
+
+pub fn euler_phi_table(n: usize) -> Vec<u64> {
+    multiplicative_table(&LinearSieve::new(n), |p, e| {
+        (p as u64).pow(e as u32 - 1) * (p as u64 - 1)
+    })
+}
+
+pub fn moebius_table(n: usize) -> Vec<i64> {
+    multiplicative_table(&LinearSieve::new(n), |_, e| if e == 1 { -1 } else { 0 })
+}
+
+pub fn liouville_table(n: usize) -> Vec<i64> {
+    multiplicative_table(&LinearSieve::new(n), |_, e| if e % 2 == 0 { 1 } else { -1 })
+}
+
+pub fn divisor_sigma_table(n: usize, k: u32) -> Vec<u64> {
+    multiplicative_table(&LinearSieve::new(n), |p, e| {
+        let pk = (p as u64).pow(k);
+        (0..e).fold(1, |acc, _| acc * pk + 1)
+    })
+}
+
+pub fn number_of_divisors_table(n: usize) -> Vec<u64> {
+    multiplicative_table(&LinearSieve::new(n), |_, e| e as u64 + 1)
+}
+
+// Dirichlet 積 (f * g)(n) = Σ_{d | n} f(d) g(n / d) (添字 0 は使わない)
+pub fn dirichlet_convolution<T: Semiring + Clone>(f: &[T], g: &[T]) -> Vec<T> {
+    let n = f.len().min(g.len());
+    let mut h = vec![T::zero(); n];
+    for i in 1..n {
+        for j in 1..=(n - 1) / i {
+            h[i * j] += f[i].clone() * g[j].clone();
+        }
+    }
+    h
+}
+
+// Dirichlet 積に関する逆元 (f(1) は可逆であること)
+pub fn dirichlet_inverse<T: Ring + Div<Output = T> + Clone>(f: &[T]) -> Vec<T> {
+    let n = f.len();
+    // acc[m] = Σ_{d | m, d < m} f(m / d) g(d)
+    let mut acc = vec![T::zero(); n];
+    let mut g = vec![T::zero(); n];
+    for i in 1..n {
+        g[i] = if i == 1 {
+            T::one() / f[1].clone()
+        } else {
+            -acc[i].clone() / f[1].clone()
+        };
+        for j in 2..=(n - 1) / i {
+            acc[i * j] += f[j].clone() * g[i].clone();
+        }
+    }
+    g
+}
+
+// Du の篩: Σ_{d=1}^{n} S(n / d) = h(n) となる S(n) を O(n^(2/3)) で求める
+// (prefix は n^(2/3) 程度までの S の表)
+fn du_sieve(n: u64, prefix: &[i128], h: impl Fn(u64) -> i128) -> i128 {
+    let threshold = prefix.len() as u64 - 1;
+    if n <= threshold {
+        return prefix[n as usize];
+    }
+    // large[i] = S(n / i) (n / i > threshold)
+    let count = (n / (threshold + 1)) as usize;
+    let mut large = vec![0i128; count + 1];
+    for i in (1..=count).rev() {
+        let v = n / i as u64;
+        let mut s = h(v);
+        let mut d = 2;
+        while d <= v {
+            let q = v / d;
+            let next = v / q;
+            let sq = if q <= threshold {
+                prefix[q as usize]
+            } else {
+                large[i * d as usize]
+            };
+            s -= (next - d + 1) as i128 * sq;
+            d = next + 1;
+        }
+        large[i] = s;
+    }
+    large[1]
+}
+
+fn du_threshold(n: u64) -> usize {
+    ((n as f64).powf(2.0 / 3.0) as usize).max(1)
+}
+
+// Σ_{i=1}^{n} phi(i)
+pub fn euler_phi_sum(n: u64) -> u128 {
+    let mut prefix: Vec<i128> = euler_phi_table(du_threshold(n))
+        .into_iter()
+        .map(i128::from)
+        .collect();
+    for i in 1..prefix.len() {
+        prefix[i] += prefix[i - 1];
+    }
+    // phi * 1 = id
+    du_sieve(n, &prefix, |v| v as i128 * (v as i128 + 1) / 2) as u128
+}
+
+// Mertens 関数 M(n) = Σ_{i=1}^{n} mu(i)
+pub fn mertens(n: u64) -> i64 {
+    let mut prefix: Vec<i128> = moebius_table(du_threshold(n))
+        .into_iter()
+        .map(i128::from)
+        .collect();
+    for i in 1..prefix.len() {
+        prefix[i] += prefix[i - 1];
+    }
+    // mu * 1 = ε
+    du_sieve(n, &prefix, |_| 1) as i64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use num::Integer;
+
+    fn divisors(n: u64) -> Vec<u64> {
+        (1..=n).filter(|d| n.is_multiple_of(*d)).collect()
+    }
+
+    #[test]
+    fn functions() {
+        for n in 1..500u64 {
+            let phi = (1..=n).filter(|k| k.gcd(&n) == 1).count() as u64;
+            assert_eq!(n.euler_phi(), phi);
+            assert_eq!(n.number_of_divisors(), divisors(n).len() as u64);
+            for k in 0..3 {
+                let sigma: u64 = divisors(n).iter().map(|d| d.pow(k)).sum();
+                assert_eq!(n.divisor_sigma(k), sigma);
+            }
+            // Σ_{d | n} mu(d) = [n = 1], Σ_{d | n} λ(d) = [n は平方数]
+            let mu: i32 = divisors(n).into_iter().map(|d| d.moebius()).sum();
+            assert_eq!(mu, (n == 1) as i32);
+            let lambda: i32 = divisors(n).into_iter().map(|d| d.liouville()).sum();
+            assert_eq!(lambda, (n.isqrt().pow(2) == n) as i32);
+        }
+        assert_eq!(ZZ::from(1_000_000_007u64).pow(2).euler_phi(), {
+            let p = ZZ::from(1_000_000_007u64);
+            &p * (&p - 1)
+        });
+    }
+
+    #[test]
+    fn tables() {
+        let n = 10000;
+        let phi = euler_phi_table(n);
+        let mu = moebius_table(n);
+        let lambda = liouville_table(n);
+        let sigma = divisor_sigma_table(n, 1);
+        let d = number_of_divisors_table(n);
+        for i in 1..=n {
+            assert_eq!(phi[i], i.euler_phi() as u64);
+            assert_eq!(mu[i], i.moebius() as i64);
+            assert_eq!(lambda[i], i.liouville() as i64);
+            assert_eq!(sigma[i], i.divisor_sigma(1) as u64);
+            assert_eq!(d[i], i.number_of_divisors() as u64);
+        }
+    }
+
+    #[test]
+    fn dirichlet() {
+        let n = 3000;
+        let one = vec![1i64; n + 1];
+        let id: Vec<i64> = (0..=n as i64).collect();
+        let mu = moebius_table(n);
+        let phi: Vec<i64> = euler_phi_table(n).into_iter().map(|x| x as i64).collect();
+        let mut epsilon = vec![0i64; n + 1];
+        epsilon[1] = 1;
+        assert_eq!(dirichlet_convolution(&mu, &one)[1..], epsilon[1..]);
+        assert_eq!(dirichlet_convolution(&phi, &one)[1..], id[1..]);
+        assert_eq!(dirichlet_convolution(&mu, &id)[1..], phi[1..]);
+        assert_eq!(dirichlet_inverse(&one)[1..], mu[1..]);
+        assert_eq!(dirichlet_inverse(&mu)[1..], one[1..]);
+        let d: Vec<i64> = number_of_divisors_table(n)
+            .into_iter()
+            .map(|x| x as i64)
+            .collect();
+        let inv = dirichlet_inverse(&d);
+        assert_eq!(dirichlet_convolution(&d, &inv)[1..], epsilon[1..]);
+    }
+
+    #[test]
+    fn prefix_sums() {
+        let n = 100000;
+        let phi = euler_phi_table(n);
+        let mu = moebius_table(n);
+        let (mut phi_sum, mut mu_sum) = (0u128, 0i64);
+        for i in 1..=n {
+            phi_sum += phi[i] as u128;
+            mu_sum += mu[i];
+            if i % 997 == 0 || i == n {
+                assert_eq!(euler_phi_sum(i as u64), phi_sum);
+                assert_eq!(mertens(i as u64), mu_sum);
+            }
+        }
+        assert_eq!(euler_phi_sum(1_000_000), 303963552392);
+        assert_eq!(mertens(1_000_000_000), -222);
+    }
+}
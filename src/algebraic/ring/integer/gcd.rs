@@ -0,0 +1,388 @@
+use super::ZZ;
+use crate::algebraic::ring::EuclidDomain;
+use crate::algebraic::{One, Zero};
+use num::{Integer, PrimInt, Signed, ToPrimitive, Unsigned};
+
+// 二進 GCD (Stein のアルゴリズム)
+pub fn binary_gcd<T: PrimInt + Unsigned>(mut a: T, mut b: T) -> T {
+    if a.is_zero() {
+        return b;
+    }
+    if b.is_zero() {
+        return a;
+    }
+    let shift = (a | b).trailing_zeros();
+    a = a >> a.trailing_zeros() as usize;
+    while !b.is_zero() {
+        b = b >> b.trailing_zeros() as usize;
+        if a > b {
+            (a, b) = (b, a);
+        }
+        b = b - a;
+    }
+    a << shift as usize
+}
+
+// Lehmer の GCD (上位 64bit で商を求めて多倍長の割り算を減らす)
+pub fn lehmer_gcd(a: &ZZ, b: &ZZ) -> ZZ {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    if a < b {
+        (a, b) = (b, a);
+    }
+    while b.bits() > 64 {
+        let shift = a.bits() - 64;
+        let mut ah = (&a >> shift).to_i128().unwrap();
+        let mut bh = (&b >> shift).to_i128().unwrap();
+        // (a, b) ← (x0 a + y0 b, x1 a + y1 b)
+        let (mut x0, mut y0, mut x1, mut y1) = (1i128, 0i128, 0i128, 1i128);
+        while bh + x1 != 0 && bh + y1 != 0 {
+            let q = (ah + x0) / (bh + x1);
+            if q != (ah + y0) / (bh + y1) {
+                break;
+            }
+            (x0, x1) = (x1, x0 - q * x1);
+            (y0, y1) = (y1, y0 - q * y1);
+            (ah, bh) = (bh, ah - q * bh);
+        }
+        if y0 == 0 {
+            let r = &a % &b;
+            (a, b) = (b, r);
+        } else {
+            (a, b) = (&a * x0 + &b * y0, &a * x1 + &b * y1);
+        }
+    }
+    let b = b.to_u64().unwrap();
+    if b == 0 {
+        return a;
+    }
+    let a = (a % b).to_u64().unwrap();
+    binary_gcd(a, b).into()
+}
+
+// 拡張 Euclid の互除法の結果 a x + b y = g を g >= 0, 0 <= x < |b| / g に正規化する
+fn normalize_xgcd<T: Integer + Signed + Clone>(a: T, b: T, g: T, x: T, y: T) -> (T, T, T) {
+    let (g, x, y) = if g.is_negative() {
+        (-g, -x, -y)
+    } else {
+        (g, x, y)
+    };
+    if b.is_zero() {
+        return (g, a.signum(), T::zero());
+    }
+    // x ← x - q |b| / g, y ← y + q sgn(b) a / g
+    let q = x.div_floor(&(b.clone() / g.clone()).abs());
+    let x = x - q.clone() * (b.clone() / g.clone()).abs();
+    let y = y + q * b.signum() * (a / g.clone());
+    (g, x, y)
+}
+
+impl EuclidDomain for ZZ {
+    fn gcd(lhs: Self, rhs: Self) -> Self {
+        lehmer_gcd(&lhs, &rhs)
+    }
+    fn xgcd(lhs: Self, rhs: Self) -> (Self, Self, Self) {
+        let (mut r0, mut r1) = (lhs.clone(), rhs.clone());
+        let (mut x0, mut x1) = (ZZ::one(), ZZ::zero());
+        let (mut y0, mut y1) = (ZZ::zero(), ZZ::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1);
+            (r0, r1) = (r1, r);
+            let x = x0 - &q * &x1;
+            (x0, x1) = (x1, x);
+            let y = y0 - &q * &y1;
+            (y0, y1) = (y1, y);
+        }
+        normalize_xgcd(lhs, rhs, r0, x0, y0)
+    }
+    fn lcm(lhs: Self, rhs: Self) -> Self {
+        if lhs.is_zero() || rhs.is_zero() {
+            return ZZ::zero();
+        }
+        let g = <Self as EuclidDomain>::gcd(lhs.clone(), rhs.clone());
+        (lhs / g * rhs).abs()
+    }
+    fn mod_inverse(a: Self, m: Self) -> Option<Self> {
+        assert!(m.is_positive(), "modulus must be positive");
+        let (g, x, _) = Self::xgcd(a.mod_floor(&m), m.clone());
+        g.is_one().then(|| x.mod_floor(&m))
+    }
+}
+
+macro_rules! impl_euclid_domain {
+    ($($t:ty, $u:ty;)*) => {$(
+        impl EuclidDomain for $t {
+            fn gcd(lhs: Self, rhs: Self) -> Self {
+                let g = binary_gcd(lhs.unsigned_abs(), rhs.unsigned_abs());
+                Self::try_from(g).unwrap_or_else(|_| {
+                    panic!("gcd({}, {}) = {} overflows {}", lhs, rhs, g, stringify!($t))
+                })
+            }
+            // 絶対値で互除法を行ってから符号を戻す (途中で MIN / -1 などが溢れないように)
+            fn xgcd(lhs: Self, rhs: Self) -> (Self, Self, Self) {
+                let g = <Self as EuclidDomain>::gcd(lhs, rhs);
+                if rhs == 0 {
+                    return (g, lhs.signum(), 0);
+                }
+                let (a, b) = (lhs.unsigned_abs(), rhs.unsigned_abs());
+                // a x + b y ≡ g (mod 2^N), 0 <= x < b / g
+                let (_, x, y) = <$u>::xgcd(a, b);
+                // lhs < 0 なら x を b / g - x に取り替えて -a x + b y = g にする
+                let (x, y) = if lhs >= 0 || x == 0 {
+                    (x, y)
+                } else {
+                    (b / g as $u - x, y.wrapping_add(a / g as $u))
+                };
+                let y = if rhs < 0 { y.wrapping_neg() } else { y };
+                (g, x as $t, y as $t)
+            }
+            fn lcm(lhs: Self, rhs: Self) -> Self {
+                if lhs == 0 || rhs == 0 {
+                    return 0;
+                }
+                (lhs / <Self as EuclidDomain>::gcd(lhs, rhs) * rhs).abs()
+            }
+            fn mod_inverse(a: Self, m: Self) -> Option<Self> {
+                assert!(m > 0, "modulus must be positive");
+                let (g, x, _) = Self::xgcd(a.rem_euclid(m), m);
+                (g == 1).then_some(x)
+            }
+        }
+    )*};
+}
+impl_euclid_domain!(i8, u8; i16, u16; i32, u32; i64, u64; i128, u128; isize, usize;);
+
+// 符号なし整数では xgcd の y は負になりうるので 2^N を法とした値を返す
+// (lhs x + rhs y = g は wrapping な演算で成り立つ)
+macro_rules! impl_euclid_domain_unsigned {
+    ($($t:ty)*) => {$(
+        impl EuclidDomain for $t {
+            fn gcd(lhs: Self, rhs: Self) -> Self {
+                binary_gcd(lhs, rhs)
+            }
+            fn xgcd(lhs: Self, rhs: Self) -> (Self, Self, Self) {
+                if rhs == 0 {
+                    return (lhs, (lhs != 0) as Self, 0);
+                }
+                // 係数の符号は交互に変わるので絶対値だけを持つ
+                let (mut r0, mut r1) = (lhs, rhs);
+                let (mut x0, mut x1): (Self, Self) = (1, 0);
+                let (mut y0, mut y1): (Self, Self) = (0, 1);
+                let mut negative = false;
+                while r1 != 0 {
+                    let q = r0 / r1;
+                    (r0, r1) = (r1, r0 - q * r1);
+                    (x0, x1) = (x1, x0 + q * x1);
+                    (y0, y1) = (y1, y0 + q * y1);
+                    negative = !negative;
+                }
+                // negative なら lhs (-x0) + rhs y0 = g, そうでなければ lhs x0 - rhs y0 = g
+                let g = r0;
+                if !negative {
+                    (g, x0, y0.wrapping_neg())
+                } else if x0 == 0 {
+                    (g, 0, y0)
+                } else {
+                    (g, rhs / g - x0, (lhs / g - y0).wrapping_neg())
+                }
+            }
+            fn lcm(lhs: Self, rhs: Self) -> Self {
+                if lhs == 0 || rhs == 0 {
+                    return 0;
+                }
+                lhs / binary_gcd(lhs, rhs) * rhs
+            }
+            fn mod_inverse(a: Self, m: Self) -> Option<Self> {
+                assert!(m > 0, "modulus must be positive");
+                let (g, x, _) = Self::xgcd(a % m, m);
+                (g == 1).then_some(x)
+            }
+        }
+    )*};
+}
+impl_euclid_domain_unsigned!(u8 u16 u32 u64 u128 usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    fn check_xgcd<T: EuclidDomain + Integer + Signed + Clone + std::fmt::Debug>(a: T, b: T) {
+        let (g, x, y) = T::xgcd(a.clone(), b.clone());
+        assert_eq!(g, a.gcd(&b), "{:?} {:?}", a, b);
+        assert_eq!(a.clone() * x.clone() + b.clone() * y, g);
+        if !b.is_zero() {
+            assert!(!x.is_negative() && x < (b / g).abs());
+        }
+    }
+
+    #[test]
+    fn primitive_random() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10000 {
+            let common: i64 = rng.gen_range(1..1000);
+            let a = rng.gen_range(-1_000_000i64..1_000_000) * common;
+            let b = rng.gen_range(-1_000_000i64..1_000_000) * common;
+            assert_eq!(<i64 as EuclidDomain>::gcd(a, b), Integer::gcd(&a, &b));
+            assert_eq!(<i64 as EuclidDomain>::lcm(a, b), Integer::lcm(&a, &b));
+            check_xgcd(a, b);
+            let (a, b) = ((a % 40000) as i32, (b % 40000) as i32);
+            assert_eq!(<i32 as EuclidDomain>::gcd(a, b), Integer::gcd(&a, &b));
+            check_xgcd(a, b);
+            let (ua, ub) = (a.unsigned_abs(), b.unsigned_abs());
+            assert_eq!(binary_gcd(ua, ub), Integer::gcd(&ua, &ub));
+        }
+        for a in -10i8..=10 {
+            for b in -10i8..=10 {
+                assert_eq!(<i8 as EuclidDomain>::gcd(a, b), Integer::gcd(&a, &b));
+                check_xgcd(a, b);
+            }
+        }
+        assert_eq!(<i64 as EuclidDomain>::gcd(4, 6), 2);
+        assert_eq!(<i64 as EuclidDomain>::gcd(0, 0), 0);
+        assert_eq!(i64::xgcd(0, 0), (0, 0, 0));
+        assert_eq!(i64::xgcd(-5, 0), (5, -1, 0));
+        assert_eq!(i64::xgcd(240, 46), (2, 14, -73));
+        assert_eq!(<i64 as EuclidDomain>::gcd(i64::MIN, 12), 4);
+        // gcd が |MIN| になるときは表せないので panic する
+        assert!(std::panic::catch_unwind(|| <i64 as EuclidDomain>::gcd(i64::MIN, 0)).is_err());
+        assert!(std::panic::catch_unwind(|| i64::xgcd(i64::MIN, i64::MIN)).is_err());
+        assert!(std::panic::catch_unwind(|| i8::xgcd(0, i8::MIN)).is_err());
+    }
+
+    // MIN を含む組も i128 で検算する
+    fn check_xgcd_wide<T: EuclidDomain + Copy + Into<i128>>(a: T, b: T) {
+        let (g, x, y) = T::xgcd(a, b);
+        let (a, b, g, x, y) = (a.into(), b.into(), g.into(), x.into(), y.into());
+        assert_eq!(g, Integer::gcd(&a, &b), "{} {}", a, b);
+        assert_eq!(a * x + b * y, g, "{} {}", a, b);
+        if b != 0 {
+            assert!(0 <= x && x < (b / g).abs(), "{} {}", a, b);
+        }
+    }
+
+    #[test]
+    fn primitive_extremes() {
+        for a in i8::MIN..=i8::MAX {
+            for b in i8::MIN..=i8::MAX {
+                if !(a == i8::MIN || a == 0) || !(b == i8::MIN || b == 0) {
+                    check_xgcd_wide(a, b);
+                }
+            }
+        }
+        let mut rng = rand::thread_rng();
+        let mut values = vec![i64::MIN, i64::MIN + 1, -1, 0, 1, i64::MAX - 1, i64::MAX];
+        values.extend((0..20).map(|_| rng.gen::<i64>()));
+        for &a in &values {
+            for &b in &values {
+                if !(a == i64::MIN || a == 0) || !(b == i64::MIN || b == 0) {
+                    check_xgcd_wide(a, b);
+                    check_xgcd_wide(a as i32, b as i32);
+                }
+            }
+        }
+        assert_eq!(i64::xgcd(i64::MIN, 1), (1, 0, 1));
+        assert_eq!(i64::xgcd(i64::MIN, -1), (1, 0, -1));
+        assert_eq!(i64::xgcd(-1, i64::MIN), (1, i64::MAX, -1));
+        assert_eq!(i128::xgcd(i128::MIN, 3), (1, 1, i128::MAX / 3 + 1));
+    }
+
+    #[test]
+    fn unsigned_random() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..10000 {
+            let common: u32 = rng.gen_range(1..1000);
+            let a = rng.gen_range(0..1_000_000u32) * common;
+            let b = rng.gen_range(0..1_000_000u32) * common;
+            assert_eq!(<u32 as EuclidDomain>::gcd(a, b), Integer::gcd(&a, &b));
+            let (la, lb) = (a as u64, b as u64);
+            assert_eq!(<u64 as EuclidDomain>::lcm(la, lb), Integer::lcm(&la, &lb));
+            // y は 2^32 を法として符号付きの結果と一致する
+            let (g, x, y) = i64::xgcd(a.into(), b.into());
+            assert_eq!(u32::xgcd(a, b), (g as u32, x as u32, y as u32));
+            let (a, b) = (a as u64 * rng.gen::<u32>() as u64, b as u64);
+            let (g, x, y) = u64::xgcd(a, b);
+            assert_eq!(g, Integer::gcd(&a, &b));
+            assert_eq!(a.wrapping_mul(x).wrapping_add(b.wrapping_mul(y)), g);
+            if b != 0 {
+                assert!(x < b / g);
+            }
+        }
+        for a in 0..=u8::MAX {
+            for b in 0..=u8::MAX {
+                let (g, x, y) = u8::xgcd(a, b);
+                assert_eq!(g, Integer::gcd(&a, &b));
+                assert_eq!(a.wrapping_mul(x).wrapping_add(b.wrapping_mul(y)), g);
+                let (g, x, y) = i16::xgcd(a.into(), b.into());
+                assert_eq!(u8::xgcd(a, b), (g as u8, x as u8, y as u8));
+            }
+        }
+        assert_eq!(<u64 as EuclidDomain>::gcd(u64::MAX, u64::MAX), u64::MAX);
+        assert_eq!(u128::xgcd(u128::MAX, 1), (1, 0, 1));
+        assert_eq!(usize::lcm_all([4, 6, 10]), 60);
+        for m in 1..100u64 {
+            for a in 0..200u64 {
+                assert_eq!(
+                    u64::mod_inverse(a, m),
+                    i64::mod_inverse(a as i64, m as i64).map(|x| x as u64)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn bigint_random() {
+        let mut rng = rand::thread_rng();
+        let random = |rng: &mut rand::rngs::ThreadRng, max_bits: usize| -> ZZ {
+            let bits = rng.gen_range(1..max_bits);
+            let words: Vec<u32> = (0..bits.div_ceil(32)).map(|_| rng.gen()).collect();
+            let x = ZZ::from_slice(num::bigint::Sign::Plus, &words);
+            if rng.gen() {
+                -x
+            } else {
+                x
+            }
+        };
+        for _ in 0..300 {
+            let common = random(&mut rng, 300);
+            let a = random(&mut rng, 1000) * &common;
+            let b = random(&mut rng, 1000) * &common;
+            assert_eq!(
+                <ZZ as EuclidDomain>::gcd(a.clone(), b.clone()),
+                Integer::gcd(&a, &b)
+            );
+            assert_eq!(
+                <ZZ as EuclidDomain>::lcm(a.clone(), b.clone()),
+                Integer::lcm(&a, &b)
+            );
+            check_xgcd(a, b);
+        }
+        // 連続する Fibonacci 数は互除法の最悪ケース
+        let (mut f0, mut f1) = (ZZ::zero(), ZZ::one());
+        for _ in 0..2000 {
+            (f0, f1) = (f1.clone(), f0 + f1);
+        }
+        assert!(<ZZ as EuclidDomain>::gcd(f0.clone(), f1.clone()).is_one());
+        check_xgcd(f0, f1);
+    }
+
+    #[test]
+    fn mod_inverse() {
+        for m in 1..100i64 {
+            for a in -100..100i64 {
+                let expected = (0..m).find(|x| (a * x - 1).rem_euclid(m) == 0);
+                assert_eq!(i64::mod_inverse(a, m), expected);
+                assert_eq!(ZZ::mod_inverse(a.into(), m.into()), expected.map(ZZ::from));
+            }
+        }
+    }
+
+    #[test]
+    fn iterators() {
+        assert_eq!(i64::gcd_all([12, -18, 30]), 6);
+        assert_eq!(i64::gcd_all([]), 0);
+        assert_eq!(i64::lcm_all([4, 6, -10]), 60);
+        assert_eq!(i64::lcm_all([]), 1);
+        let v: Vec<ZZ> = (1..=30).map(ZZ::from).collect();
+        assert_eq!(ZZ::lcm_all(v), ZZ::from(2329089562800u64));
+    }
+}
@@ -0,0 +1,119 @@
+use super::convolution::{
+    convolution_int, karatsuba, Convolution, THREE_PRIMES_PRODUCT, THREE_PRIMES_THRESHOLD,
+};
+use super::{Factor, UFD};
+use crate::algebraic::{One, ScalarMul, ScalarPow};
+use num::{
+    complex::Complex64, traits::NumAssign, BigInt, BigRational, FromPrimitive, Integer, ToPrimitive,
+};
+
+pub mod arithmetic;
+pub mod gcd;
+pub mod prime;
+pub mod sieve;
+
+pub type Int = BigInt;
+pub type Rational = BigRational;
+pub type Real = f64;
+pub type Complex = Complex64;
+
+pub type ZZ = Int;
+pub type QQ = Rational;
+pub type RR = Real;
+pub type CC = Complex;
+
+impl<T: NumAssign + FromPrimitive + Clone> ScalarMul for T {
+    fn scalar_mul(&self, e: usize) -> Self {
+        self.clone().mul(T::from_usize(e).unwrap())
+    }
+}
+impl<T: NumAssign + FromPrimitive + Clone> ScalarPow for T {
+    fn scalar_pow(&self, mut e: usize) -> Self {
+        let mut result = T::one();
+        let mut cur = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= cur.clone();
+            }
+            e >>= 1;
+            cur *= cur.clone();
+        }
+        result
+    }
+}
+
+impl<T: NumAssign + FromPrimitive + ToPrimitive + Clone> Convolution for T {
+    fn convolution(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        // 係数が十分小さい整数なら 3 素数 NTT で計算する
+        if lhs.len().min(rhs.len()) > THREE_PRIMES_THRESHOLD {
+            let to_i64 = |v: &[Self]| -> Option<Vec<i64>> {
+                v.iter()
+                    .map(|x| x.to_i64().filter(|&y| T::from_i64(y).as_ref() == Some(x)))
+                    .collect()
+            };
+            if let (Some(a), Some(b)) = (to_i64(lhs), to_i64(rhs)) {
+                let max_abs = |v: &[i64]| v.iter().map(|x| x.unsigned_abs()).max().unwrap() as u128;
+                let bound = max_abs(&a)
+                    .checked_mul(max_abs(&b))
+                    .and_then(|x| x.checked_mul(a.len().min(b.len()) as u128));
+                if bound.is_some_and(|x| x < THREE_PRIMES_PRODUCT / 2) {
+                    return convolution_int(&a, &b)
+                        .into_iter()
+                        .map(|x| T::from_i128(x).unwrap())
+                        .collect();
+                }
+            }
+        }
+        karatsuba(lhs, rhs)
+    }
+}
+
+pub trait CarmichaelLambda {
+    fn carmichael_lambda(self) -> Self;
+}
+
+impl CarmichaelLambda for ZZ {
+    fn carmichael_lambda(self) -> Self {
+        let mut res = ZZ::one();
+        for Factor { p, e } in self.factors() {
+            let lambda = if p == ZZ::from(2) && e >= 3 {
+                ZZ::one() << (e - 2)
+            } else {
+                p.pow(e as u32 - 1) * (p - 1)
+            };
+            res = res.lcm(&lambda);
+        }
+        res
+    }
+}
+
+macro_rules! impl_carmichael_lambda {
+    ($($t:ty)*) => {$(
+        impl CarmichaelLambda for $t {
+            fn carmichael_lambda(self) -> Self {
+                let lambda = ZZ::from(self).carmichael_lambda();
+                <$t>::try_from(lambda).unwrap()
+            }
+        }
+    )*};
+}
+impl_carmichael_lambda!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+#[test]
+fn test_small() {
+    let suite: &[(u64, &[Factor<u64>])] = &[
+        (0, &[]),
+        (1, &[]),
+        (2, &[Factor { p: 2, e: 1 }]),
+        (3, &[Factor { p: 3, e: 1 }]),
+        (4, &[Factor { p: 2, e: 2 }]),
+        (5, &[Factor { p: 5, e: 1 }]),
+        (10, &[Factor { p: 2, e: 1 }, Factor { p: 5, e: 1 }]),
+        (100, &[Factor { p: 2, e: 2 }, Factor { p: 5, e: 2 }]),
+        (200, &[Factor { p: 2, e: 3 }, Factor { p: 5, e: 2 }]),
+    ];
+    for (n, expected) in suite {
+        let actual: Vec<_> = n.factors();
+        assert_eq!(&actual, expected);
+    }
+}
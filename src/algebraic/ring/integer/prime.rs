@@ -0,0 +1,552 @@
+use super::ZZ;
+use crate::algebraic::ring::integer_mod::dynamic_mod_int::Barrett;
+use crate::algebraic::ring::{Factor, UFD};
+use crate::algebraic::{One, Zero};
+use num::{Integer, Signed, ToPrimitive};
+
+// 試し割りする小さい素数の上限
+const TRIAL_DIVISION_BOUND: u64 = 256;
+
+pub trait Primality {
+    fn is_prime(&self) -> bool;
+}
+
+pub trait NextPrime: Sized {
+    // self より大きい最小の素数
+    fn next_prime(&self) -> Self;
+    // self より小さい最大の素数
+    fn previous_prime(&self) -> Option<Self>;
+}
+
+// 決定的 Miller–Rabin (64bit ではこの 7 底で十分)
+pub fn is_prime_u64(n: u64) -> bool {
+    if n < 64 {
+        return (1u64 << n) & 0x28208a20a08a28ac != 0;
+    }
+    if n.is_multiple_of(2) || n.is_multiple_of(3) || n.is_multiple_of(5) {
+        return false;
+    }
+    let ctx = Barrett::new(n);
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    [2, 325, 9375, 28178, 450775, 9780504, 1795265022]
+        .iter()
+        .all(|&a| strong_probable_prime_u64(&ctx, a % n, d, s))
+}
+
+fn strong_probable_prime_u64(ctx: &Barrett, a: u64, d: u64, s: u32) -> bool {
+    let n = ctx.modulus();
+    if a == 0 {
+        return true;
+    }
+    let mut x = ctx.pow(a, d);
+    if x == 1 || x == n - 1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = ctx.mul(x, x);
+        if x == n - 1 {
+            return true;
+        }
+    }
+    false
+}
+
+// Jacobi 記号 (a/n) (n は正の奇数)
+pub fn jacobi(a: &ZZ, n: &ZZ) -> i32 {
+    assert!(n.is_positive() && n.is_odd());
+    let (mut a, mut n) = (a.mod_floor(n), n.clone());
+    let mut res = 1;
+    while !a.is_zero() {
+        let s = a.trailing_zeros().unwrap();
+        a >>= s;
+        let n8 = (&n % 8u32).to_u32().unwrap();
+        if s % 2 == 1 && (n8 == 3 || n8 == 5) {
+            res = -res;
+        }
+        if (&a % 4u32).to_u32() == Some(3) && n8 % 4 == 3 {
+            res = -res;
+        }
+        (a, n) = (&n % &a, a);
+    }
+    if n.is_one() {
+        res
+    } else {
+        0
+    }
+}
+
+// Baillie–PSW (底 2 の強擬素数判定 + 強 Lucas 擬素数判定)
+pub fn is_prime_bpsw(n: &ZZ) -> bool {
+    if let Some(n) = n.to_u64() {
+        return is_prime_u64(n);
+    }
+    if n.is_negative() {
+        return false;
+    }
+    for p in (2..TRIAL_DIVISION_BOUND).filter(|&p| is_prime_u64(p)) {
+        if n.is_multiple_of(&p.into()) {
+            return false;
+        }
+    }
+    let nm1: ZZ = n - 1;
+    let s = nm1.trailing_zeros().unwrap();
+    let d = &nm1 >> s;
+    let mut x = ZZ::from(2).modpow(&d, n);
+    if !x.is_one() && x != nm1 {
+        let mut ok = false;
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == nm1 {
+                ok = true;
+                break;
+            }
+        }
+        if !ok {
+            return false;
+        }
+    }
+    is_strong_lucas_probable_prime(n)
+}
+
+// Selfridge の方法で D, P = 1, Q = (1 - D) / 4 を選んだ強 Lucas 判定
+fn is_strong_lucas_probable_prime(n: &ZZ) -> bool {
+    // 平方数では (D/n) = -1 となる D が存在しない
+    if n.sqrt().pow(2) == *n {
+        return false;
+    }
+    let mut d = ZZ::from(5);
+    loop {
+        match jacobi(&d, n) {
+            -1 => break,
+            0 if d.abs() != *n => return false,
+            _ => {}
+        }
+        d = if d.is_positive() { -d - 2 } else { -d + 2 };
+    }
+    let q: ZZ = (1 - &d) / 4;
+    let half = |x: ZZ| -> ZZ {
+        let x: ZZ = if x.is_odd() { x + n } else { x };
+        (x >> 1u32).mod_floor(n)
+    };
+
+    // n + 1 = k 2^s として U_k, V_k を二進法で計算する (P = 1)
+    let np1: ZZ = n + 1;
+    let s = np1.trailing_zeros().unwrap();
+    let k = &np1 >> s;
+    let (mut u, mut v, mut qk) = (ZZ::one(), ZZ::one(), q.mod_floor(n));
+    for i in (0..k.bits() - 1).rev() {
+        u = &u * &v % n;
+        v = (&v * &v - (&qk << 1u32)).mod_floor(n);
+        qk = &qk * &qk % n;
+        if k.bit(i) {
+            (u, v) = (half(&u + &v), half(&d * &u + &v));
+            qk = (&qk * &q).mod_floor(n);
+        }
+    }
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        v = (&v * &v - (&qk << 1u32)).mod_floor(n);
+        if v.is_zero() {
+            return true;
+        }
+        qk = &qk * &qk % n;
+    }
+    false
+}
+
+impl Primality for ZZ {
+    fn is_prime(&self) -> bool {
+        is_prime_bpsw(self)
+    }
+}
+
+impl NextPrime for ZZ {
+    fn next_prime(&self) -> Self {
+        if self < &ZZ::from(2) {
+            return 2.into();
+        }
+        let mut n: ZZ = self + 1;
+        if n.is_even() && n != ZZ::from(2) {
+            n += 1;
+        }
+        while !n.is_prime() {
+            n += 2;
+        }
+        n
+    }
+
+    fn previous_prime(&self) -> Option<Self> {
+        if self <= &ZZ::from(2) {
+            return None;
+        }
+        if self == &ZZ::from(3) {
+            return Some(2.into());
+        }
+        let mut n: ZZ = self - 1;
+        if n.is_even() {
+            n -= 1;
+        }
+        while !n.is_prime() {
+            n -= 2;
+        }
+        Some(n)
+    }
+}
+
+macro_rules! impl_primality {
+    ($($t:ty)*) => {$(
+        impl Primality for $t {
+            fn is_prime(&self) -> bool {
+                match u64::try_from(*self) {
+                    Ok(n) => is_prime_u64(n),
+                    Err(_) => *self > 0 && is_prime_bpsw(&ZZ::from(*self)),
+                }
+            }
+        }
+    )*};
+}
+impl_primality!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+// Pollard の ρ 法 (Brent の変種) で合成数 n の非自明な約数を 1 つ求める
+fn pollard_rho_u64(n: u64) -> u64 {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+    const BLOCK: u64 = 128;
+    let ctx = Barrett::new(n);
+    for c in 1..n {
+        let f = |x: u64| ctx.add(ctx.mul(x, x), c);
+        let (mut x, mut y, mut ys) = (0, 2, 0);
+        let (mut g, mut q, mut r) = (1, 1, 1);
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BLOCK.min(r - k) {
+                    y = f(y);
+                    q = ctx.mul(q, x.abs_diff(y));
+                }
+                g = q.gcd(&n);
+                k += BLOCK;
+            }
+            r <<= 1;
+        }
+        // まとめて掛けた積が n の倍数になったら 1 歩ずつ戻る
+        if g == n {
+            g = 1;
+            while g == 1 {
+                ys = f(ys);
+                g = x.abs_diff(ys).gcd(&n);
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+fn pollard_rho_bigint(n: &ZZ) -> ZZ {
+    if let Some(m) = n.to_u64() {
+        return pollard_rho_u64(m).into();
+    }
+    if n.is_even() {
+        return 2.into();
+    }
+    const BLOCK: u64 = 128;
+    let mut c = ZZ::one();
+    loop {
+        let f = |x: &ZZ| (x * x + &c) % n;
+        let (mut x, mut y, mut ys) = (ZZ::zero(), ZZ::from(2), ZZ::zero());
+        let (mut g, mut q, mut r) = (ZZ::one(), ZZ::one(), 1u64);
+        while g.is_one() {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g.is_one() {
+                ys = y.clone();
+                for _ in 0..BLOCK.min(r - k) {
+                    y = f(&y);
+                    q = q * (&x - &y).abs() % n;
+                }
+                g = q.gcd(n);
+                k += BLOCK;
+            }
+            r <<= 1;
+        }
+        if g == *n {
+            g = ZZ::one();
+            while g.is_one() {
+                ys = f(&ys);
+                g = (&x - &ys).abs().gcd(n);
+            }
+        }
+        if g != *n {
+            return g;
+        }
+        c += 1;
+    }
+}
+
+fn collect_factors<T: Ord>(mut primes: Vec<T>) -> Vec<Factor<T>> {
+    primes.sort();
+    let mut res: Vec<Factor<T>> = Vec::new();
+    for p in primes {
+        match res.last_mut() {
+            Some(f) if f.p == p => f.e += 1,
+            _ => res.push(Factor { p, e: 1 }),
+        }
+    }
+    res
+}
+
+// 小さい素数で試し割りしてから残りを ρ 法で分解する
+pub fn factorize_u64(mut n: u64) -> Vec<Factor<u64>> {
+    let mut primes = Vec::new();
+    if n <= 1 {
+        return vec![];
+    }
+    for p in 2..TRIAL_DIVISION_BOUND.min(n + 1) {
+        while n.is_multiple_of(p) {
+            primes.push(p);
+            n /= p;
+        }
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m == 1 {
+            continue;
+        }
+        if is_prime_u64(m) {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho_u64(m);
+        stack.push(d);
+        stack.push(m / d);
+    }
+    collect_factors(primes)
+}
+
+pub fn factorize_bigint(n: &ZZ) -> Vec<Factor<ZZ>> {
+    if !n.is_positive() {
+        return vec![];
+    }
+    if let Some(n) = n.to_u64() {
+        return factorize_u64(n)
+            .into_iter()
+            .map(|Factor { p, e }| Factor { p: p.into(), e })
+            .collect();
+    }
+    let mut n = n.clone();
+    let mut primes = Vec::new();
+    for p in 2..TRIAL_DIVISION_BOUND {
+        let p = ZZ::from(p);
+        while n.is_multiple_of(&p) {
+            primes.push(p.clone());
+            n /= &p;
+        }
+    }
+    let mut stack = vec![n];
+    while let Some(m) = stack.pop() {
+        if m.is_one() {
+            continue;
+        }
+        if m.is_prime() {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho_bigint(&m);
+        stack.push(&m / &d);
+        stack.push(d);
+    }
+    collect_factors(primes)
+}
+
+impl UFD for ZZ {
+    fn factors(self) -> Vec<Factor<Self>> {
+        factorize_bigint(&self)
+    }
+}
+
+macro_rules! impl_ufd {
+    ($($t:ty)*) => {$(
+        impl UFD for $t {
+            fn factors(self) -> Vec<Factor<Self>> {
+                match u64::try_from(self) {
+                    Ok(n) => factorize_u64(n)
+                        .into_iter()
+                        .map(|Factor { p, e }| Factor { p: p as $t, e })
+                        .collect(),
+                    Err(_) => factorize_bigint(&ZZ::from(self))
+                        .into_iter()
+                        .map(|Factor { p, e }| Factor { p: <$t>::try_from(p).unwrap(), e })
+                        .collect(),
+                }
+            }
+        }
+    )*};
+}
+impl_ufd!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    fn is_prime_naive(n: u64) -> bool {
+        n >= 2
+            && (2..)
+                .take_while(|d| d * d <= n)
+                .all(|d| !n.is_multiple_of(d))
+    }
+
+    #[test]
+    fn small_primes() {
+        for n in 0..100000u64 {
+            assert_eq!(n.is_prime(), is_prime_naive(n), "{}", n);
+            assert_eq!(ZZ::from(n).is_prime(), is_prime_naive(n), "{}", n);
+        }
+        assert!(!(-7i32).is_prime());
+    }
+
+    #[test]
+    fn pseudoprimes() {
+        // 底 2 の強擬素数や Carmichael 数
+        for n in [
+            2047u64,
+            3215031751,
+            2152302898747,
+            3474749660383,
+            341550071728321,
+            3825123056546413051,
+            561,
+            41041,
+        ] {
+            assert!(!n.is_prime(), "{}", n);
+        }
+        for n in [(1u64 << 61) - 1, 998244353, 1_000_000_007, u64::MAX - 58] {
+            assert!(n.is_prime(), "{}", n);
+        }
+        // 強 Lucas 擬素数は Miller–Rabin で弾かれる
+        let lucas_pseudoprimes = [5459u64, 5777, 10877, 16109, 18971];
+        for n in (3..20000u64).step_by(2) {
+            let expected = is_prime_naive(n) || lucas_pseudoprimes.contains(&n);
+            assert_eq!(is_strong_lucas_probable_prime(&n.into()), expected, "{}", n);
+        }
+    }
+
+    #[test]
+    fn big_primes() {
+        let mersenne = |p: u32| -> ZZ { (ZZ::one() << p) - 1 };
+        for p in [89, 107, 127, 521] {
+            assert!(mersenne(p).is_prime(), "2^{} - 1", p);
+        }
+        for p in [67, 101, 257] {
+            assert!(!mersenne(p).is_prime(), "2^{} - 1", p);
+        }
+        assert!(!((ZZ::one() << 128u32) + 1u32).is_prime());
+        let p = ZZ::from((1u64 << 61) - 1);
+        assert!(!(&p * &p).is_prime());
+        assert!(!(&p * ZZ::from(u64::MAX - 58)).is_prime());
+    }
+
+    #[test]
+    fn next_and_previous_prime() {
+        let zz = |n: i64| ZZ::from(n);
+        assert_eq!(zz(-5).next_prime(), zz(2));
+        assert_eq!(zz(2).next_prime(), zz(3));
+        assert_eq!(zz(3).next_prime(), zz(5));
+        assert_eq!(zz(1_000_000_000).next_prime(), zz(1_000_000_007));
+        assert_eq!(zz(2).previous_prime(), None);
+        assert_eq!(zz(3).previous_prime(), Some(zz(2)));
+        assert_eq!(zz(4).previous_prime(), Some(zz(3)));
+        assert_eq!(zz(1_000_000_007).previous_prime(), Some(zz(999999937)));
+        let n = ZZ::one() << 64u32;
+        assert_eq!(n.next_prime(), &n + 13u32);
+        assert_eq!(n.previous_prime(), Some(&n - 59u32));
+        for n in 0..1000i64 {
+            let next = (n + 1..).find(|m| m.is_prime()).unwrap();
+            assert_eq!(zz(n).next_prime(), zz(next));
+            let prev = (0..n).rev().find(|m| m.is_prime()).map(zz);
+            assert_eq!(zz(n).previous_prime(), prev);
+        }
+    }
+
+    fn check_factors<T: UFD + Clone + Ord + One + std::ops::Mul<Output = T> + std::fmt::Debug>(
+        n: T,
+        factors: &[Factor<T>],
+    ) {
+        let mut product = T::one();
+        for f in factors {
+            for _ in 0..f.e {
+                product = product * f.p.clone();
+            }
+        }
+        assert_eq!(product, n);
+        assert!(factors.windows(2).all(|w| w[0].p < w[1].p));
+    }
+
+    #[test]
+    fn factorize_random_u64() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let n: u64 = rng.gen_range(1..u64::MAX);
+            let factors = n.factors();
+            check_factors(n, &factors);
+            assert!(factors.iter().all(|f| f.p.is_prime()));
+        }
+        let p = (1u64 << 31) - 1;
+        assert_eq!(
+            (p * ((1 << 31) + 11)).factors(),
+            vec![
+                Factor { p, e: 1 },
+                Factor {
+                    p: (1 << 31) + 11,
+                    e: 1
+                }
+            ]
+        );
+        assert_eq!((1u64 << 63).factors(), vec![Factor { p: 2, e: 63 }]);
+    }
+
+    #[test]
+    fn factorize_bigint() {
+        let n: ZZ = (ZZ::one() << 64) + 1;
+        assert_eq!(
+            n.factors(),
+            vec![
+                Factor {
+                    p: 274177.into(),
+                    e: 1
+                },
+                Factor {
+                    p: 67280421310721u64.into(),
+                    e: 1
+                }
+            ]
+        );
+        let n: ZZ = ZZ::from(1_000_000_007u64).pow(3) * ZZ::from((1u64 << 61) - 1) * 720;
+        let factors = n.clone().factors();
+        check_factors(n, &factors);
+        assert_eq!(factors.len(), 5);
+        assert_eq!(
+            factors[3],
+            Factor {
+                p: 1_000_000_007.into(),
+                e: 3
+            }
+        );
+        assert_eq!((-12i64).factors(), vec![]);
+        assert_eq!((u128::MAX).factors().len(), 9);
+    }
+}
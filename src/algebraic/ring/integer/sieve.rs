@@ -0,0 +1,221 @@
+use crate::algebraic::ring::Factor;
+
+// 区間篩の 1 区間に含む奇数の個数
+const SEGMENT_SIZE: usize = 1 << 15;
+
+// limit 以下の素数を小さい順に列挙する区間篩 (メモリは O(√limit))
+pub struct Primes {
+    limit: u64,
+    // √limit 以下の奇素数と, それぞれ次に消す奇数
+    base: Vec<(u64, u64)>,
+    // segment[i] は low + 2i が素数かどうか
+    segment: Vec<bool>,
+    low: u64,
+    pos: usize,
+    two: bool,
+}
+
+impl Primes {
+    pub fn new(limit: u64) -> Self {
+        let r = limit.isqrt() as usize;
+        let mut is_prime = vec![true; r + 1];
+        let mut base = Vec::new();
+        for p in (3..=r).step_by(2) {
+            if is_prime[p] {
+                base.push((p as u64, (p * p) as u64));
+                (p * p..=r).step_by(p).for_each(|j| is_prime[j] = false);
+            }
+        }
+        Self {
+            limit,
+            base,
+            segment: Vec::new(),
+            low: 1,
+            pos: 0,
+            two: limit >= 2,
+        }
+    }
+
+    fn sieve_segment(&mut self) {
+        let high = self.low + 2 * SEGMENT_SIZE as u64;
+        self.segment = vec![true; SEGMENT_SIZE];
+        if self.low == 1 {
+            self.segment[0] = false;
+        }
+        for (p, next) in self.base.iter_mut() {
+            while *next < high {
+                self.segment[((*next - self.low) / 2) as usize] = false;
+                *next += 2 * *p;
+            }
+        }
+        self.pos = 0;
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+    fn next(&mut self) -> Option<u64> {
+        if self.two {
+            self.two = false;
+            return Some(2);
+        }
+        loop {
+            if self.pos == self.segment.len() {
+                if !self.segment.is_empty() {
+                    self.low += 2 * SEGMENT_SIZE as u64;
+                }
+                if self.low > self.limit {
+                    return None;
+                }
+                self.sieve_segment();
+            }
+            let n = self.low + 2 * self.pos as u64;
+            self.pos += 1;
+            if n > self.limit {
+                self.segment.clear();
+                (self.low, self.pos) = (u64::MAX, 0);
+                return None;
+            }
+            if self.segment[self.pos - 1] {
+                return Some(n);
+            }
+        }
+    }
+}
+
+pub fn primes_up_to(limit: u64) -> Vec<u64> {
+    Primes::new(limit).collect()
+}
+
+// 線形篩による最小素因数表
+pub struct LinearSieve {
+    spf: Vec<u32>,
+    primes: Vec<u32>,
+}
+
+impl LinearSieve {
+    pub fn new(n: usize) -> Self {
+        let mut spf = vec![0u32; n + 1];
+        let mut primes = Vec::new();
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i as u32);
+            }
+            for &p in &primes {
+                if p > spf[i] || i * p as usize > n {
+                    break;
+                }
+                spf[i * p as usize] = p;
+            }
+        }
+        Self { spf, primes }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.spf.len() - 1
+    }
+
+    pub fn primes(&self) -> &[u32] {
+        &self.primes
+    }
+
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.spf[n] as usize == n
+    }
+
+    // n >= 2 の最小素因数
+    pub fn smallest_prime_factor(&self, n: usize) -> usize {
+        self.spf[n] as usize
+    }
+
+    // O(log n) で素因数分解する
+    pub fn factors(&self, mut n: usize) -> Vec<Factor<usize>> {
+        let mut res: Vec<Factor<usize>> = Vec::new();
+        while n > 1 {
+            let p = self.spf[n] as usize;
+            match res.last_mut() {
+                Some(f) if f.p == p => f.e += 1,
+                _ => res.push(Factor { p, e: 1 }),
+            }
+            n /= p;
+        }
+        res
+    }
+}
+
+// x 以下の素数の個数 (Lucy_Hedgehog, O(x^(3/4)))
+pub fn prime_pi(x: u64) -> u64 {
+    if x < 2 {
+        return 0;
+    }
+    let r = x.isqrt() as usize;
+    // small[v] = S(v), large[i] = S(x / i) (S は篩い残った 2 以上の数の個数)
+    let mut small: Vec<u64> = (0..=r as u64).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = std::iter::once(0)
+        .chain((1..=r as u64).map(|i| x / i - 1))
+        .collect();
+    for p in 2..=r {
+        if small[p] == small[p - 1] {
+            continue;
+        }
+        let pc = small[p - 1];
+        let p2 = (p * p) as u64;
+        let lim = (r as u64).min(x / p2) as usize;
+        for i in 1..=lim {
+            let d = i * p;
+            let s = if d <= r {
+                large[d]
+            } else {
+                small[(x / d as u64) as usize]
+            };
+            large[i] -= s - pc;
+        }
+        for v in (p * p..=r).rev() {
+            small[v] -= small[v / p] - pc;
+        }
+    }
+    large[1]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::prime::Primality;
+    use crate::algebraic::ring::UFD;
+
+    #[test]
+    fn segmented_sieve() {
+        for limit in [0, 1, 2, 3, 4, 100, 65535, 65536, 65537, 200000] {
+            let expected: Vec<u64> = (0..=limit).filter(|n| n.is_prime()).collect();
+            assert_eq!(primes_up_to(limit), expected, "{}", limit);
+        }
+        assert_eq!(Primes::new(10_000_000).count(), 664579);
+    }
+
+    #[test]
+    fn linear_sieve() {
+        let sieve = LinearSieve::new(100000);
+        assert_eq!(sieve.limit(), 100000);
+        let primes: Vec<u64> = sieve.primes().iter().map(|&p| p as u64).collect();
+        assert_eq!(primes, primes_up_to(100000));
+        for n in 2..=100000 {
+            assert_eq!(sieve.factors(n), n.factors());
+            assert_eq!(sieve.is_prime(n), n.is_prime());
+        }
+        assert_eq!(sieve.smallest_prime_factor(91), 7);
+    }
+
+    #[test]
+    fn prime_counting() {
+        let expected = [
+            0, 4, 25, 168, 1229, 9592, 78498, 664579, 5761455, 50847534, 455052511,
+        ];
+        for (k, &pi) in expected.iter().enumerate() {
+            assert_eq!(prime_pi(10u64.pow(k as u32)), pi);
+        }
+        for x in 0..1000 {
+            assert_eq!(prime_pi(x), primes_up_to(x).len() as u64);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use super::mod_int::ModInt;
+use super::{IntegerMod, IntegerModRing};
+use crate::algebraic::ring::crt::crt;
+use crate::algebraic::ring::integer::ZZ;
+use crate::algebraic::ring::{Factor, UFD};
+use crate::algebraic::{One, Zero};
+use num::{Integer, Signed};
+use std::collections::HashMap;
+
+// g^x = h (0 <= x < order) を baby-step giant-step で解く
+fn baby_step_giant_step(g: &IntegerMod, h: &IntegerMod, order: &ZZ) -> Option<ZZ> {
+    let m = order.sqrt() + 1;
+    let mut table = HashMap::new();
+    let mut cur = g.parent().one();
+    let mut j = ZZ::zero();
+    while j < m {
+        table.entry(cur.value()).or_insert_with(|| j.clone());
+        cur *= g.clone();
+        j += 1;
+    }
+    let factor = g.pow(&-m.clone());
+    let mut gamma = h.clone();
+    let mut i = ZZ::zero();
+    while i < m {
+        if let Some(j) = table.get(&gamma.value()) {
+            return Some((&i * &m + j).mod_floor(order));
+        }
+        gamma *= factor.clone();
+        i += 1;
+    }
+    None
+}
+
+// g の位数 order を素因数分解して Pohlig–Hellman で解く (g は単元)
+pub(super) fn pohlig_hellman(g: &IntegerMod, h: &IntegerMod, order: &ZZ) -> Option<ZZ> {
+    let mut congruences = Vec::new();
+    for Factor { p, e } in order.clone().factors() {
+        let pe = p.pow(e as u32);
+        let cofactor = order / &pe;
+        let (g0, h0) = (g.pow(&cofactor), h.pow(&cofactor));
+        // 位数 p の部分群で p 進の各桁を求める
+        let gamma = g0.pow(&(&pe / &p));
+        let mut xk = ZZ::zero();
+        let mut pk = ZZ::one();
+        for k in 0..e {
+            let hk = (g0.pow(&-xk.clone()) * h0.clone()).pow(&p.pow(e as u32 - 1 - k as u32));
+            let d = baby_step_giant_step(&gamma, &hk, &p)?;
+            xk += d * &pk;
+            pk *= &p;
+        }
+        congruences.push((xk, pe));
+    }
+    // x ≡ xk (mod p^e) を合わせる
+    crt(&congruences).map(|(x, _)| x)
+}
+
+impl IntegerMod {
+    // base^x = self を満たす最小の x >= 0 (存在しなければ None)
+    pub fn discrete_log(&self, base: &IntegerMod) -> Option<ZZ> {
+        let n = self.common_modulus(base).ok()?;
+        if n.is_zero() {
+            return discrete_log_integer(&self.num, &base.num);
+        }
+        let ring = IntegerModRing::new(n.clone());
+        let (g, h) = (ring.elem(base.value()), ring.elem(self.value()));
+
+        // 小さい x は直接調べる (g と n が互いに素でない部分はここで尽きる)
+        let mut cur = ring.one();
+        for x in 0..=n.bits() {
+            if cur == h {
+                return Some(x.into());
+            }
+            cur *= g.clone();
+        }
+
+        // g^k * g^(x-k) = h で g と互いに素になるまで法を割っていく
+        let (mut m, mut b, mut coeff) = (n, h.value(), ZZ::one());
+        let mut k = 0u64;
+        loop {
+            let d = g.value().gcd(&m);
+            if d.is_one() {
+                break;
+            }
+            if !b.is_multiple_of(&d) {
+                return None;
+            }
+            m /= &d;
+            b /= &d;
+            coeff = coeff * (g.value() / &d) % &m;
+            k += 1;
+        }
+        let ring = IntegerModRing::new(m);
+        let g = ring.elem(g.value());
+        let h = ring.elem(b) / ring.elem(coeff);
+        let order = g.multiplicative_order()?;
+        let y = pohlig_hellman(&g, &h, &order)?;
+        if g.pow(&y) == h {
+            Some(y + k)
+        } else {
+            None
+        }
+    }
+}
+
+// Z 上の g^x = h
+fn discrete_log_integer(h: &ZZ, g: &ZZ) -> Option<ZZ> {
+    // 0^0 = 1, 0^x = 0 (x >= 1)
+    if g.is_zero() {
+        return if h.is_one() {
+            Some(ZZ::zero())
+        } else if h.is_zero() {
+            Some(ZZ::one())
+        } else {
+            None
+        };
+    }
+    let mut cur = ZZ::one();
+    let mut x = ZZ::zero();
+    loop {
+        if &cur == h {
+            return Some(x);
+        }
+        if g.abs() <= ZZ::one() && x >= 2.into() || cur.abs() > h.abs() {
+            return None;
+        }
+        cur *= g;
+        x += 1;
+    }
+}
+
+impl ModInt {
+    // base^x = self を満たす最小の x >= 0
+    pub fn discrete_log(&self, base: ModInt) -> Option<i64> {
+        let ring = IntegerModRing::new(ModInt::modulus().into());
+        ring.elem(self.value().into())
+            .discrete_log(&ring.elem(base.value().into()))
+            .map(|x| i64::try_from(x).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer_mod::mod_int::set_mod_int;
+    use rand::Rng;
+
+    #[test]
+    fn small_moduli() {
+        for n in 1..40u64 {
+            let ring = IntegerModRing::new(n.into());
+            for g in ring.iter() {
+                let mut expected = HashMap::new();
+                let mut cur = ring.one();
+                for x in 0..2 * n {
+                    expected.entry(cur.value()).or_insert(x);
+                    cur *= g.clone();
+                }
+                for h in ring.iter() {
+                    let x = h.discrete_log(&g);
+                    assert_eq!(
+                        x,
+                        expected.get(&h.value()).map(|&x| x.into()),
+                        "{} {} {}",
+                        n,
+                        g,
+                        h
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_prime() {
+        let mut rng = rand::thread_rng();
+        for p in [998244353u64, 1_000_000_007, (1 << 61) - 1] {
+            let ring = IntegerModRing::new(p.into());
+            let g = ring.multiplicative_generator().unwrap();
+            for _ in 0..3 {
+                let x: u64 = rng.gen_range(0..p - 1);
+                let h = g.pow(&x.into());
+                assert_eq!(h.discrete_log(&g), Some(x.into()));
+            }
+        }
+    }
+
+    #[test]
+    fn mod_int() {
+        set_mod_int(1_000_000_007i64);
+        let g = ModInt::from(5i64);
+        let h = g.pow(123456789i64);
+        assert_eq!(h.discrete_log(g), Some(123456789));
+        assert_eq!(ModInt::from(0i64).discrete_log(g), None);
+    }
+
+    #[test]
+    fn integers() {
+        let ring = IntegerModRing::new(0.into());
+        let (g, h) = (ring.elem((-2).into()), ring.elem((-32).into()));
+        assert_eq!(h.discrete_log(&g), Some(5.into()));
+        assert_eq!(ring.elem(3.into()).discrete_log(&g), None);
+        // 0^0 = 1, 0^1 = 0
+        let zero = ring.zero();
+        assert_eq!(ring.one().discrete_log(&zero), Some(0.into()));
+        assert_eq!(zero.discrete_log(&zero), Some(1.into()));
+        assert_eq!(ring.elem(2.into()).discrete_log(&zero), None);
+        assert_eq!(zero.discrete_log(&g), None);
+    }
+}
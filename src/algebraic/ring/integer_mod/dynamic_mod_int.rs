@@ -0,0 +1,320 @@
+use crate::algebraic::ring::convolution::{karatsuba, Convolution};
+use crate::algebraic::{One, ScalarMul, ScalarPow, Zero};
+use num::{Num, NumCast};
+use std::cell::Cell;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Barrett reduction (法は 64bit 全域)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Barrett {
+    m: u64,
+    // floor((2^128 - 1) / m)
+    im: u128,
+}
+
+impl Barrett {
+    pub const fn new(m: u64) -> Self {
+        assert!(m > 0, "modulus must be positive");
+        Self {
+            m,
+            im: u128::MAX / m as u128,
+        }
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> u64 {
+        self.m
+    }
+
+    // x mod m
+    #[inline]
+    pub fn reduce(&self, x: u128) -> u64 {
+        const MASK: u128 = (1 << 64) - 1;
+        let (x1, x0) = (x >> 64, x & MASK);
+        let (i1, i0) = (self.im >> 64, self.im & MASK);
+        let mid1 = x1 * i0;
+        let mid2 = x0 * i1;
+        let carry = ((x0 * i0) >> 64) + (mid1 & MASK) + (mid2 & MASK);
+        let q = x1 * i1 + (mid1 >> 64) + (mid2 >> 64) + (carry >> 64);
+
+        let mut r = x - q * self.m as u128;
+        while r >= self.m as u128 {
+            r -= self.m as u128;
+        }
+        r as u64
+    }
+
+    #[inline]
+    pub fn add(&self, a: u64, b: u64) -> u64 {
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= self.m {
+            sum.wrapping_sub(self.m)
+        } else {
+            sum
+        }
+    }
+
+    #[inline]
+    pub fn sub(&self, a: u64, b: u64) -> u64 {
+        if a >= b {
+            a - b
+        } else {
+            a.wrapping_sub(b).wrapping_add(self.m)
+        }
+    }
+
+    #[inline]
+    pub fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+
+    pub fn pow(&self, a: u64, mut e: u64) -> u64 {
+        let mut result = self.reduce(1);
+        let mut cur = self.reduce(a as u128);
+        while e > 0 {
+            if e & 1 == 1 {
+                result = self.mul(result, cur);
+            }
+            e >>= 1;
+            cur = self.mul(cur, cur);
+        }
+        result
+    }
+
+    // 逆元 (m と互いに素でなければ None)
+    pub fn inv(&self, a: u64) -> Option<u64> {
+        let (mut a, mut b) = (self.reduce(a as u128) as i128, self.m as i128);
+        let (mut x, mut y) = (1i128, 0i128);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        if a == 1 {
+            Some(x.rem_euclid(self.m as i128) as u64)
+        } else {
+            None
+        }
+    }
+}
+
+thread_local!(
+    static BARRETT: Cell<Barrett> = const { Cell::new(Barrett::new(1)) };
+);
+
+pub fn set_dynamic_mod_int(m: u64) {
+    BARRETT.with(|x| x.set(Barrett::new(m)));
+}
+#[inline]
+fn barrett() -> Barrett {
+    BARRETT.with(|x| x.get())
+}
+
+// 実行時に法を決める剰余環の元 (法は set_dynamic_mod_int で設定する)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DynamicModInt(u64);
+
+impl DynamicModInt {
+    #[inline]
+    pub fn modulus() -> u64 {
+        barrett().modulus()
+    }
+
+    // v < modulus を仮定する
+    #[inline]
+    pub fn raw(v: u64) -> Self {
+        Self(v)
+    }
+
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(&self, e: u64) -> Self {
+        Self(barrett().pow(self.0, e))
+    }
+
+    pub fn inv(&self) -> Option<Self> {
+        barrett().inv(self.0).map(Self)
+    }
+}
+
+impl<T: Num + NumCast> From<T> for DynamicModInt {
+    fn from(v: T) -> Self {
+        let ctx = barrett();
+        match v.to_u128() {
+            Some(v) => Self(ctx.reduce(v)),
+            None => {
+                let v = v.to_i128().unwrap();
+                Self(ctx.sub(0, ctx.reduce(v.unsigned_abs())))
+            }
+        }
+    }
+}
+
+impl Zero for DynamicModInt {
+    fn zero() -> Self {
+        Self(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for DynamicModInt {
+    fn one() -> Self {
+        Self(barrett().reduce(1))
+    }
+}
+
+impl<T: Into<DynamicModInt>> AddAssign<T> for DynamicModInt {
+    fn add_assign(&mut self, rhs: T) {
+        self.0 = barrett().add(self.0, rhs.into().0);
+    }
+}
+
+impl<T: Into<DynamicModInt>> SubAssign<T> for DynamicModInt {
+    fn sub_assign(&mut self, rhs: T) {
+        self.0 = barrett().sub(self.0, rhs.into().0);
+    }
+}
+
+impl<T: Into<DynamicModInt>> MulAssign<T> for DynamicModInt {
+    fn mul_assign(&mut self, rhs: T) {
+        self.0 = barrett().mul(self.0, rhs.into().0);
+    }
+}
+
+impl<T: Into<DynamicModInt>> DivAssign<T> for DynamicModInt {
+    fn div_assign(&mut self, rhs: T) {
+        let inv = rhs
+            .into()
+            .inv()
+            .expect("division by a non-invertible element");
+        self.mul_assign(inv);
+    }
+}
+
+impl<T: Into<DynamicModInt>> Add<T> for DynamicModInt {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self {
+        let mut res = self;
+        res += rhs;
+        res
+    }
+}
+
+impl Neg for DynamicModInt {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self(barrett().sub(0, self.0))
+    }
+}
+
+impl<T: Into<DynamicModInt>> Sub<T> for DynamicModInt {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self {
+        let mut res = self;
+        res -= rhs;
+        res
+    }
+}
+
+impl<T: Into<DynamicModInt>> Mul<T> for DynamicModInt {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self {
+        let mut res = self;
+        res *= rhs;
+        res
+    }
+}
+
+impl<T: Into<DynamicModInt>> Div<T> for DynamicModInt {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self {
+        let mut res = self;
+        res /= rhs;
+        res
+    }
+}
+
+impl ScalarMul for DynamicModInt {
+    fn scalar_mul(&self, e: usize) -> Self {
+        *self * e
+    }
+}
+
+impl ScalarPow for DynamicModInt {
+    fn scalar_pow(&self, e: usize) -> Self {
+        self.pow(e as u64)
+    }
+}
+
+impl Convolution for DynamicModInt {
+    fn convolution(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        karatsuba(lhs, rhs)
+    }
+}
+
+impl Display for DynamicModInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn barrett_reduce() {
+        let mut rng = rand::thread_rng();
+        for m in [
+            1,
+            2,
+            3,
+            998244353,
+            1 << 32,
+            (1 << 61) - 1,
+            u64::MAX - 58,
+            u64::MAX,
+        ] {
+            let ctx = Barrett::new(m);
+            for _ in 0..10000 {
+                let x: u128 = rng.gen();
+                assert_eq!(ctx.reduce(x) as u128, x % m as u128);
+                let (a, b) = (rng.gen_range(0..m), rng.gen_range(0..m));
+                assert_eq!(ctx.mul(a, b) as u128, a as u128 * b as u128 % m as u128);
+                assert_eq!(ctx.add(a, b) as u128, (a as u128 + b as u128) % m as u128);
+                assert_eq!(
+                    ctx.sub(a, b) as u128,
+                    (a as u128 + m as u128 - b as u128) % m as u128
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn random_ops_64bit() {
+        let mut rng = rand::thread_rng();
+        // 2^64 - 59 は素数
+        let m = u64::MAX - 58;
+        set_dynamic_mod_int(m);
+        for _ in 0..10000 {
+            let x: u64 = rng.gen_range(0..m);
+            let y: u64 = rng.gen_range(1..m);
+            let (mx, my) = (DynamicModInt::from(x), DynamicModInt::from(y));
+            let mul = (x as u128 * y as u128 % m as u128) as u64;
+            assert_eq!((mx * my).value(), mul);
+            assert_eq!((mx * y).value(), mul);
+            assert_eq!((mx / my * my).value(), x);
+            assert_eq!((mx + my - y).value(), x);
+            assert_eq!((-mx + x).value(), 0);
+        }
+        assert_eq!(DynamicModInt::from(-1).value(), m - 1);
+        assert_eq!(DynamicModInt::from(3).pow(m - 1), DynamicModInt::one());
+    }
+}
@@ -0,0 +1,849 @@
+mod discrete_log;
+pub mod dynamic_mod_int;
+mod root;
+pub mod static_mod_int;
+mod unit_group;
+
+use super::convolution::{convolution_mod, karatsuba, Convolution, THREE_PRIMES_THRESHOLD};
+use super::integer::ZZ;
+use crate::algebraic::ring::{EuclidDomain, UFD};
+use crate::algebraic::{One, ScalarMul, ScalarPow, Zero};
+use num::bigint::Sign;
+use num::{Integer, Signed};
+use rand::Rng;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Z/nZ (n = 0 のときは Z)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegerModRing {
+    order: ZZ,
+}
+impl IntegerModRing {
+    pub fn new(modulus: ZZ) -> Self {
+        Self {
+            order: modulus.abs(),
+        }
+    }
+    // value を既約剰余にした元
+    pub fn elem(&self, value: ZZ) -> IntegerMod {
+        IntegerMod::new(value, self.order.clone())
+    }
+    pub fn zero(&self) -> IntegerMod {
+        self.elem(0.into())
+    }
+    pub fn one(&self) -> IntegerMod {
+        self.elem(1.into())
+    }
+    // 法が 0 の元 (整数) をこの環に落とす (法が異なれば Err)
+    pub fn coerce(&self, x: &IntegerMod) -> Result<IntegerMod, IntegerModError> {
+        if x.modulus.is_zero() || x.modulus == self.order {
+            Ok(self.elem(x.num.clone()))
+        } else {
+            Err(IntegerModError::ModulusMismatch(
+                x.modulus.clone(),
+                self.order.clone(),
+            ))
+        }
+    }
+    pub fn random_element(&self) -> IntegerMod {
+        let mut rng = rand::thread_rng();
+        if self.order.is_zero() {
+            return self.elem(rng.gen::<i64>().into());
+        }
+        // 64bit 余分に取って偏りを抑える
+        let bytes: Vec<u8> = (0..self.order.bits() / 8 + 9).map(|_| rng.gen()).collect();
+        self.elem(ZZ::from_bytes_le(Sign::Plus, &bytes))
+    }
+    // 元を 0, 1, 2, ... の順に列挙する (Z のときは 0, 1, -1, 2, -2, ...)
+    pub fn iter(&self) -> impl Iterator<Item = IntegerMod> + '_ {
+        let mut next = ZZ::zero();
+        std::iter::from_fn(move || {
+            if self.order.is_zero() {
+                let cur = next.clone();
+                next = if next.is_positive() {
+                    -next.clone()
+                } else {
+                    -next.clone() + 1
+                };
+                return Some(self.elem(cur));
+            }
+            if next >= self.order {
+                return None;
+            }
+            let cur = next.clone();
+            next += 1;
+            Some(self.elem(cur))
+        })
+    }
+    pub fn order(&self) -> ZZ {
+        self.order.clone()
+    }
+    pub fn characteristic(&self) -> ZZ {
+        self.order.clone()
+    }
+    pub fn cardinality(&self) -> ZZ {
+        self.order.clone()
+    }
+    pub fn krull_dimension(&self) -> ZZ {
+        0.into()
+    }
+    pub fn is_noetherian(&self) -> bool {
+        true
+    }
+    pub fn is_prime_field(&self) -> bool {
+        let factors = self.order.clone().factors();
+        factors.len() == 1 && factors[0].e == 1
+    }
+    pub fn category() {
+        // Join of Category of finite commutative rings
+        //     and Category of subquotients of monoids
+        //     and Category of quotients of semigroups
+        //     and Category of finite enumerated sets
+    }
+    // pub fn charpoly(self) -> Polynomial<ZZ> {
+    //     Polynomial::from(vec![-self, ZZ::one()])
+    // }
+}
+
+pub type Zmod = IntegerMod;
+// 法が 0 の元は整数として扱い, 法が n の元との演算では Z/nZ に落とす
+#[derive(Debug, Clone)]
+pub struct IntegerMod {
+    num: ZZ,
+    modulus: ZZ,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IntegerModError {
+    ModulusMismatch(ZZ, ZZ),
+    NotInvertible(IntegerMod),
+}
+
+impl Display for IntegerModError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ModulusMismatch(lhs, rhs) => {
+                write!(f, "modulus mismatch: Z/{}Z and Z/{}Z", lhs, rhs)
+            }
+            Self::NotInvertible(x) => write!(f, "{} is not invertible", x),
+        }
+    }
+}
+
+impl std::error::Error for IntegerModError {}
+
+impl IntegerMod {
+    fn new(value: ZZ, modulus: ZZ) -> Self {
+        let num = if modulus.is_zero() {
+            value
+        } else {
+            value.mod_floor(&modulus)
+        };
+        Self { num, modulus }
+    }
+    pub fn value(&self) -> ZZ {
+        self.num.clone()
+    }
+    pub fn modulus(&self) -> ZZ {
+        self.modulus.clone()
+    }
+    pub fn parent(&self) -> IntegerModRing {
+        IntegerModRing::new(self.modulus.clone())
+    }
+
+    fn common_modulus(&self, rhs: &Self) -> Result<ZZ, IntegerModError> {
+        if rhs.modulus.is_zero() || self.modulus == rhs.modulus {
+            Ok(self.modulus.clone())
+        } else if self.modulus.is_zero() {
+            Ok(rhs.modulus.clone())
+        } else {
+            Err(IntegerModError::ModulusMismatch(
+                self.modulus.clone(),
+                rhs.modulus.clone(),
+            ))
+        }
+    }
+    pub fn try_add(&self, rhs: &Self) -> Result<Self, IntegerModError> {
+        let modulus = self.common_modulus(rhs)?;
+        Ok(Self::new(&self.num + &rhs.num, modulus))
+    }
+    pub fn try_sub(&self, rhs: &Self) -> Result<Self, IntegerModError> {
+        let modulus = self.common_modulus(rhs)?;
+        Ok(Self::new(&self.num - &rhs.num, modulus))
+    }
+    pub fn try_mul(&self, rhs: &Self) -> Result<Self, IntegerModError> {
+        let modulus = self.common_modulus(rhs)?;
+        Ok(Self::new(&self.num * &rhs.num, modulus))
+    }
+    pub fn try_div(&self, rhs: &Self) -> Result<Self, IntegerModError> {
+        let modulus = self.common_modulus(rhs)?;
+        let inv = Self::new(rhs.num.clone(), modulus).try_inverse()?;
+        self.try_mul(&inv)
+    }
+    // 冪乗 (負の指数は逆元の冪, 可逆でなければ panic する)
+    pub fn pow(&self, e: &ZZ) -> Self {
+        if e.is_negative() {
+            let inv = self.try_inverse().unwrap_or_else(|e| panic!("{}", e));
+            return inv.pow(&-e);
+        }
+        if self.modulus.is_zero() {
+            return Self::new(self.num.pow(u32::try_from(e).unwrap()), 0.into());
+        }
+        Self::new(self.num.modpow(e, &self.modulus), self.modulus.clone())
+    }
+    pub fn try_inverse(&self) -> Result<Self, IntegerModError> {
+        if self.modulus.is_zero() {
+            return if self.num.abs().is_one() {
+                Ok(self.clone())
+            } else {
+                Err(IntegerModError::NotInvertible(self.clone()))
+            };
+        }
+        match ZZ::mod_inverse(self.num.clone(), self.modulus.abs()) {
+            Some(x) => Ok(Self::new(x, self.modulus.clone())),
+            None => Err(IntegerModError::NotInvertible(self.clone())),
+        }
+    }
+}
+impl TryFrom<IntegerMod> for ZZ {
+    type Error = ();
+    fn try_from(value: IntegerMod) -> Result<Self, Self::Error> {
+        if value.modulus == 0.into() {
+            Ok(value.num)
+        } else {
+            Err(())
+        }
+    }
+}
+
+// 法が 0 の元は演算と同じく相手の法に落として比較する (法が異なれば等しくない)
+// そのため Z の元を挟むと推移律は成り立たない (Zero::zero() が法 0 なので Matrix 等のために必要)
+impl PartialEq for IntegerMod {
+    fn eq(&self, other: &Self) -> bool {
+        match self.try_sub(other) {
+            Ok(diff) => diff.num.is_zero(),
+            Err(_) => false,
+        }
+    }
+}
+
+impl Eq for IntegerMod {}
+
+impl Zero for IntegerMod {
+    fn zero() -> Self {
+        Self {
+            num: 0.into(),
+            modulus: 0.into(),
+        }
+    }
+    fn is_zero(&self) -> bool {
+        self.num == 0.into()
+    }
+    fn set_zero(&mut self) {
+        self.num = 0.into();
+    }
+}
+
+impl One for IntegerMod {
+    fn one() -> Self {
+        Self {
+            num: 1.into(),
+            modulus: 0.into(),
+        }
+    }
+    fn is_one(&self) -> bool {
+        Self::new(1.into(), self.modulus.clone()).num == self.num
+    }
+}
+
+// 演算子 (+=, -=, *=, /= と +, -, *, /) は法が異なる, または割る数が可逆でないと panic する
+// 失敗しうる場合は IntegerModError を返す try_add, try_sub, try_mul, try_div を使う
+impl AddAssign for IntegerMod {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.try_add(&rhs).unwrap_or_else(|e| panic!("{}", e));
+    }
+}
+
+impl SubAssign for IntegerMod {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.try_sub(&rhs).unwrap_or_else(|e| panic!("{}", e));
+    }
+}
+
+impl MulAssign for IntegerMod {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.try_mul(&rhs).unwrap_or_else(|e| panic!("{}", e));
+    }
+}
+
+impl DivAssign for IntegerMod {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.try_div(&rhs).unwrap_or_else(|e| panic!("{}", e));
+    }
+}
+
+impl Add for IntegerMod {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp += rhs;
+        tmp
+    }
+}
+
+impl Neg for IntegerMod {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(-self.num, self.modulus)
+    }
+}
+
+impl Sub for IntegerMod {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp -= rhs;
+        tmp
+    }
+}
+
+impl Mul for IntegerMod {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp *= rhs;
+        tmp
+    }
+}
+
+impl Div for IntegerMod {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp /= rhs;
+        tmp
+    }
+}
+
+impl ScalarMul for IntegerMod {
+    fn scalar_mul(&self, rhs: usize) -> Self {
+        Self::new(rhs.into(), self.modulus.clone()) * self.clone()
+    }
+}
+
+impl ScalarPow for IntegerMod {
+    fn scalar_pow(&self, mut e: usize) -> Self {
+        let mut result = Self::new(1.into(), self.modulus.clone());
+        let mut cur = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= cur.clone();
+            }
+            e >>= 1;
+            cur *= cur.clone();
+        }
+        result
+    }
+}
+
+impl Convolution for IntegerMod {
+    fn convolution(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        if lhs.is_empty() || rhs.is_empty() {
+            return vec![];
+        }
+        let modulus = lhs
+            .iter()
+            .chain(rhs)
+            .map(|x| &x.modulus)
+            .find(|m| !m.is_zero())
+            .cloned()
+            .unwrap_or_default();
+        // 法が 32bit に収まれば NTT (3 素数 NTT + Garner), それ以外は整数上で掛けてから落とす
+        match u32::try_from(&modulus) {
+            Ok(m) if m > 0 && lhs.len().min(rhs.len()) > THREE_PRIMES_THRESHOLD => {
+                let to_u32 = |v: &[Self]| -> Vec<u32> {
+                    v.iter()
+                        .map(|x| u32::try_from(x.num.mod_floor(&modulus)).unwrap())
+                        .collect()
+                };
+                convolution_mod(&to_u32(lhs), &to_u32(rhs), m)
+                    .into_iter()
+                    .map(|x| Self::new(x.into(), modulus.clone()))
+                    .collect()
+            }
+            _ => {
+                let to_zz = |v: &[Self]| -> Vec<ZZ> { v.iter().map(|x| x.value()).collect() };
+                karatsuba(&to_zz(lhs), &to_zz(rhs))
+                    .into_iter()
+                    .map(|x| Self::new(x, modulus.clone()))
+                    .collect()
+            }
+        }
+    }
+}
+
+impl Display for IntegerMod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} mod {}", self.num, self.modulus)
+    }
+}
+
+pub mod mod_int {
+    use super::dynamic_mod_int::{set_dynamic_mod_int, DynamicModInt};
+    use num::{Num, NumCast};
+
+    type ModInternalNum = i64;
+
+    // 法を設定する (DynamicModInt と同じ法を共有する)
+    pub fn set_mod_int<T: ToInternalNum>(v: T) {
+        let m = v.to_internal_num();
+        assert!(m > 0, "modulus must be positive");
+        set_dynamic_mod_int(m as u64);
+    }
+
+    // DynamicModInt を i64 でやり取りするためのラッパー (法は i64 に収まること)
+    #[derive(Debug, Clone, Copy)]
+    pub struct ModInt(DynamicModInt);
+
+    impl ModInt {
+        fn internal_new(v: ModInternalNum) -> Self {
+            Self(DynamicModInt::from(v))
+        }
+
+        // 負の指数は逆元の冪
+        pub fn internal_pow(&self, e: ModInternalNum) -> Self {
+            let base = if e < 0 {
+                self.0.inv().expect("power of a non-invertible element")
+            } else {
+                self.0
+            };
+            Self(base.pow(e.unsigned_abs()))
+        }
+
+        pub fn pow<T>(&self, e: T) -> Self
+        where
+            T: ToInternalNum,
+        {
+            self.internal_pow(e.to_internal_num())
+        }
+
+        pub fn value(&self) -> ModInternalNum {
+            ModInternalNum::try_from(self.0.value()).expect("modulus does not fit in i64")
+        }
+
+        pub fn modulus() -> ModInternalNum {
+            ModInternalNum::try_from(DynamicModInt::modulus()).expect("modulus does not fit in i64")
+        }
+    }
+
+    pub trait ToInternalNum {
+        fn to_internal_num(&self) -> ModInternalNum;
+    }
+    impl ToInternalNum for ModInt {
+        fn to_internal_num(&self) -> ModInternalNum {
+            self.value()
+        }
+    }
+    impl<T: Num + NumCast> From<T> for ModInt {
+        fn from(v: T) -> Self {
+            let v = v.to_i64().unwrap();
+            Self::internal_new(v)
+        }
+    }
+    impl<T: Num + NumCast> ToInternalNum for T {
+        fn to_internal_num(&self) -> ModInternalNum {
+            self.to_i64().unwrap()
+        }
+    }
+
+    impl<T: ToInternalNum> std::ops::AddAssign<T> for ModInt {
+        fn add_assign(&mut self, rhs: T) {
+            self.0 += DynamicModInt::from(rhs.to_internal_num());
+        }
+    }
+
+    impl<T: ToInternalNum> std::ops::Add<T> for ModInt {
+        type Output = ModInt;
+        fn add(self, rhs: T) -> Self::Output {
+            let mut res = self;
+            res += rhs;
+            res
+        }
+    }
+    impl<T: ToInternalNum> std::ops::SubAssign<T> for ModInt {
+        fn sub_assign(&mut self, rhs: T) {
+            self.0 -= DynamicModInt::from(rhs.to_internal_num());
+        }
+    }
+    impl<T: ToInternalNum> std::ops::Sub<T> for ModInt {
+        type Output = Self;
+        fn sub(self, rhs: T) -> Self::Output {
+            let mut res = self;
+            res -= rhs;
+            res
+        }
+    }
+    impl<T: ToInternalNum> std::ops::MulAssign<T> for ModInt {
+        fn mul_assign(&mut self, rhs: T) {
+            self.0 *= DynamicModInt::from(rhs.to_internal_num());
+        }
+    }
+    impl<T: ToInternalNum> std::ops::Mul<T> for ModInt {
+        type Output = Self;
+        fn mul(self, rhs: T) -> Self::Output {
+            let mut res = self;
+            res *= rhs;
+            res
+        }
+    }
+
+    impl<T: ToInternalNum> std::ops::DivAssign<T> for ModInt {
+        fn div_assign(&mut self, rhs: T) {
+            self.0 /= DynamicModInt::from(rhs.to_internal_num());
+        }
+    }
+
+    impl<T: ToInternalNum> std::ops::Div<T> for ModInt {
+        type Output = Self;
+        fn div(self, rhs: T) -> Self::Output {
+            let mut res = self;
+            res /= rhs;
+            res
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::mod_int::*;
+    use super::{IntegerMod, IntegerModError, IntegerModRing, ZZ};
+    use crate::algebraic::ring::matrix::Matrix;
+    use crate::algebraic::ring::polynomial::Polynomial;
+    use crate::algebraic::{One, ScalarPow, Zero};
+    use rand::distributions::Uniform;
+    use rand::Rng;
+
+    const PRIME_MOD: [i64; 3] = [1_000_000_007, 1_000_000_009, 998244353];
+    const INF: i64 = 1 << 60;
+
+    fn random_add_sub(prime_mod: i64) {
+        let mut rng = rand::thread_rng();
+        set_mod_int(prime_mod);
+        for _ in 0..10000 {
+            let x: i64 = rng.sample(Uniform::from(0..prime_mod));
+            let y: i64 = rng.sample(Uniform::from(0..prime_mod));
+
+            let mx = ModInt::from(x);
+            let my = ModInt::from(y);
+
+            assert_eq!((mx + my).value(), (x + y) % prime_mod);
+            assert_eq!((mx + y).value(), (x + y) % prime_mod);
+            assert_eq!((mx - my).value(), (x + prime_mod - y) % prime_mod);
+            assert_eq!((mx - y).value(), (x + prime_mod - y) % prime_mod);
+
+            let mut x = x;
+            let mut mx = mx;
+            x += y;
+            mx += my;
+            assert_eq!(mx.value(), x % prime_mod);
+
+            mx += y;
+            x += y;
+            assert_eq!(mx.value(), x % prime_mod);
+
+            mx -= my;
+            x = (x + prime_mod - y % prime_mod) % prime_mod;
+            assert_eq!(mx.value(), x);
+
+            mx -= y;
+            x = (x + prime_mod - y % prime_mod) % prime_mod;
+            assert_eq!(mx.value(), x);
+        }
+    }
+
+    #[test]
+    fn test_random_add_sub1() {
+        random_add_sub(PRIME_MOD[0]);
+    }
+
+    #[test]
+    fn test_random_add_sub2() {
+        random_add_sub(PRIME_MOD[1]);
+    }
+
+    #[test]
+    fn test_random_add_sub3() {
+        random_add_sub(PRIME_MOD[2]);
+    }
+
+    fn random_mul(prime_mod: i64) {
+        let mut rng = rand::thread_rng();
+        set_mod_int(prime_mod);
+        for _ in 0..10000 {
+            let x: i64 = rng.sample(Uniform::from(0..prime_mod));
+            let y: i64 = rng.sample(Uniform::from(0..prime_mod));
+
+            let mx = ModInt::from(x);
+            let my = ModInt::from(y);
+
+            assert_eq!((mx * my).value(), (x * y) % prime_mod);
+            assert_eq!((mx * y).value(), (x * y) % prime_mod);
+        }
+    }
+    #[test]
+    fn test_random_mul1() {
+        random_mul(PRIME_MOD[0]);
+    }
+    #[test]
+    fn test_random_mul2() {
+        random_mul(PRIME_MOD[1]);
+    }
+    #[test]
+    fn test_random_mul3() {
+        random_mul(PRIME_MOD[2]);
+    }
+
+    #[test]
+    fn zero_test() {
+        set_mod_int(1_000_000_007i64);
+        let a = ModInt::from(1_000_000_000i64);
+        let b = ModInt::from(7i64);
+        let c = a + b;
+        assert_eq!(c.value(), 0);
+    }
+
+    #[test]
+    fn pow_test() {
+        set_mod_int(1_000_000_007i64);
+        let a = ModInt::from(3i64);
+        let a = a.pow(4i64);
+        assert_eq!(a.value(), 81);
+    }
+
+    #[test]
+    fn div_test() {
+        set_mod_int(1_000_000_007i64);
+        for i in 1..100000i64 {
+            let mut a = ModInt::from(1i64);
+            a /= i;
+            a *= i;
+            assert_eq!(a.value(), 1);
+        }
+    }
+
+    #[test]
+    fn edge_cases() {
+        const MOD: i128 = 1_000_000_007;
+        set_mod_int(1_000_000_007i64);
+
+        let a = ModInt::from(1_000_000_000i64) * INF;
+        assert_eq!(
+            a.value(),
+            ((1_000_000_000i128 * i128::from(INF)) % MOD) as i64
+        );
+
+        let mut a = ModInt::from(1_000_000_000i64);
+        a *= INF;
+        assert_eq!(
+            a.value(),
+            ((1_000_000_000i128 * i128::from(INF)) % MOD) as i64
+        );
+
+        let a = ModInt::from(1_000_000_000i64) + INF;
+        assert_eq!(
+            a.value(),
+            ((1_000_000_000i128 + i128::from(INF)) % MOD) as i64
+        );
+
+        let mut a = ModInt::from(1_000_000_000i64);
+        a += INF;
+        assert_eq!(
+            a.value(),
+            ((1_000_000_000i128 + i128::from(INF)) % MOD) as i64
+        );
+
+        let a = ModInt::from(1_000_000_000i64) - INF;
+        assert_eq!(
+            a.value(),
+            ((1_000_000_000i128 + MOD - (INF as i128) % MOD) % MOD) as i64
+        );
+
+        let mut a = ModInt::from(1_000_000_000i64);
+        a -= INF;
+        assert_eq!(
+            a.value(),
+            ((1_000_000_000i128 + MOD - (INF as i128) % MOD) % MOD) as i64
+        );
+
+        let a = ModInt::from(1_000_000_000i64) / INF;
+        assert_eq!(a.value(), 961239577);
+
+        let mut a = ModInt::from(1_000_000_000i64);
+        a /= INF;
+        assert_eq!(a.value(), 961239577);
+    }
+
+    #[test]
+    fn overflow_guard() {
+        set_mod_int(1_000_000_007i64);
+        let a = ModInt::from(1_000_000_007i64 * 10);
+        assert_eq!(a.value(), 0);
+    }
+
+    #[test]
+    fn large_modulus() {
+        const MOD: i64 = (1 << 61) - 1;
+        set_mod_int(MOD);
+        let a = ModInt::from(MOD - 1) * (MOD - 1);
+        assert_eq!(a.value(), 1);
+        let a = ModInt::from(1i64 << 60) / 3i64 * 3i64;
+        assert_eq!(a.value(), 1 << 60);
+    }
+
+    #[test]
+    fn negative_operands() {
+        set_mod_int(1_000_000_007i64);
+        assert_eq!(ModInt::from(-3i64).value(), 1_000_000_004);
+        assert_eq!((ModInt::from(-3i64) * 2i64).value(), 1_000_000_001);
+        assert_eq!((ModInt::from(5i64) * -1i64).value(), 1_000_000_002);
+        assert_eq!((ModInt::from(5i64) + -7i64).value(), 1_000_000_005);
+        assert_eq!((ModInt::from(5i64) - -7i64).value(), 12);
+        assert_eq!((ModInt::from(-6i64) / -3i64).value(), 2);
+        assert_eq!(ModInt::from(-2i64).pow(3i64).value(), 999_999_999);
+
+        // 2^62 を超える法でも加減算が溢れない
+        const MOD: i64 = i64::MAX;
+        set_mod_int(MOD);
+        assert_eq!((ModInt::from(MOD - 1) + (MOD - 1)).value(), MOD - 2);
+        assert_eq!((ModInt::from(1i64) - (MOD - 1)).value(), 2);
+        assert_eq!((ModInt::from(-1i64) * -1i64).value(), 1);
+    }
+
+    #[test]
+    fn shares_modulus_with_dynamic_mod_int() {
+        use super::dynamic_mod_int::DynamicModInt;
+        set_mod_int(998244353i64);
+        assert_eq!(DynamicModInt::modulus(), 998244353);
+        assert_eq!(ModInt::from(-1i64).pow(-1i64).value(), 998244352);
+        assert!(std::panic::catch_unwind(|| set_mod_int(-7i64)).is_err());
+        assert!(std::panic::catch_unwind(|| set_mod_int(0i64)).is_err());
+    }
+
+    #[test]
+    fn initialize_from_various_primitives() {
+        set_mod_int(1_000_000_007);
+        let a = ModInt::from(100usize);
+        let b = ModInt::from(100i64);
+        assert_eq!(a.value(), b.value());
+    }
+
+    #[test]
+    fn ring_elements_are_reduced() {
+        let ring = IntegerModRing::new((-7).into());
+        assert_eq!(ring.order(), 7.into());
+        assert_eq!(ring.elem((-1).into()).value(), 6.into());
+        assert_eq!(ring.elem(100.into()).value(), 2.into());
+        assert_eq!((-ring.zero()).value(), 0.into());
+        assert_eq!(ring.one().parent(), ring);
+        for _ in 0..100 {
+            let x = ring.random_element();
+            assert!(x.value() >= 0.into() && x.value() < 7.into());
+        }
+        let elems: Vec<ZZ> = ring.iter().map(|x| x.value()).collect();
+        assert_eq!(elems, (0..7).map(ZZ::from).collect::<Vec<_>>());
+
+        let integers = IntegerModRing::new(0.into());
+        let elems: Vec<ZZ> = integers.iter().take(5).map(|x| x.value()).collect();
+        assert_eq!(elems, [0, 1, -1, 2, -2].map(ZZ::from));
+    }
+
+    #[test]
+    fn generic_zero_and_one() {
+        let ring = IntegerModRing::new(7.into());
+        let x = ring.elem(3.into());
+        assert_eq!(IntegerMod::zero() + x.clone(), x);
+        assert_eq!(x.clone() * IntegerMod::one(), x);
+        assert_eq!((IntegerMod::one() - x.clone()).value(), 5.into());
+        assert_eq!(ring.elem(7.into()), ring.zero());
+        assert!(ring.elem(0.into()).is_zero());
+        assert!((ring.one() / x.clone() * x.clone()).is_one());
+        assert_eq!(ring.one() / x.clone() * x.clone(), ring.one());
+        assert_eq!(ring.coerce(&IntegerMod::zero()), Ok(ring.zero()));
+        assert_eq!(ring.coerce(&IntegerMod::one()), Ok(ring.one()));
+        assert_eq!(x.clone() - x.clone(), IntegerMod::zero());
+        assert_eq!(ring.one(), IntegerMod::one());
+
+        // 末尾の 0 が落ちる
+        let f = Polynomial::from(vec![ring.one(), ring.elem(7.into())]);
+        assert_eq!(f.degree(), Some(0));
+        assert!(Polynomial::from(vec![ring.one()]).is_one());
+        assert_eq!(Polynomial::one(), Polynomial::from(vec![ring.one()]));
+        assert_eq!(x.scalar_pow(0), ring.one());
+        // Z/1Z では 0 = 1
+        let trivial = IntegerModRing::new(1.into());
+        assert_eq!(trivial.one(), trivial.zero());
+        assert!(trivial.zero().is_one());
+
+        // 行列の単位行列や行列式も法 0 の 0, 1 から作られる
+        let b = Matrix::from(vec![
+            vec![ring.elem(2.into()), ring.elem(3.into())],
+            vec![ring.elem(1.into()), ring.elem(4.into())],
+        ]);
+        assert_eq!(b.clone() * b.inverse().unwrap(), Matrix::identity(2));
+        let singular = Matrix::from(vec![
+            vec![ring.elem(1.into()), ring.elem(2.into())],
+            vec![ring.elem(2.into()), ring.elem(4.into())],
+        ]);
+        assert_eq!(singular.determinant(), ring.zero());
+    }
+
+    #[test]
+    fn mixed_modulus() {
+        let x = IntegerModRing::new(7.into()).elem(3.into());
+        let y = IntegerModRing::new(5.into()).elem(3.into());
+        assert_eq!(
+            x.try_add(&y),
+            Err(IntegerModError::ModulusMismatch(7.into(), 5.into()))
+        );
+        assert_ne!(x, y);
+        // 法が 0 の元は相手の法に落として比べる
+        let a = IntegerMod::new(5.into(), 0.into());
+        let b = IntegerModRing::new(3.into()).elem(2.into());
+        let c = IntegerModRing::new(5.into()).zero();
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_ne!(a, IntegerMod::new(2.into(), 0.into()));
+        assert_eq!(c, IntegerMod::zero());
+        assert_ne!(c, IntegerModRing::new(7.into()).zero());
+        assert_eq!(
+            IntegerModRing::new(5.into()).coerce(&IntegerMod::zero()),
+            Ok(c.clone())
+        );
+        assert_eq!(IntegerModRing::new(3.into()).coerce(&a), Ok(b));
+        assert_eq!(
+            IntegerModRing::new(3.into()).coerce(&c),
+            Err(IntegerModError::ModulusMismatch(5.into(), 3.into()))
+        );
+        let z = IntegerModRing::new(6.into()).elem(3.into());
+        assert_eq!(
+            z.try_inverse(),
+            Err(IntegerModError::NotInvertible(z.clone()))
+        );
+        assert!(std::panic::catch_unwind(|| x + y).is_err());
+        let ring5 = IntegerModRing::new(5.into());
+        let ring7 = IntegerModRing::new(7.into());
+        for res in [
+            ring5.one().try_add(&ring7.one()),
+            ring5.one().try_sub(&ring7.one()),
+            ring5.one().try_mul(&ring7.one()),
+            ring5.one().try_div(&ring7.one()),
+        ] {
+            assert_eq!(
+                res,
+                Err(IntegerModError::ModulusMismatch(5.into(), 7.into()))
+            );
+        }
+        assert!(std::panic::catch_unwind(|| ring5.one() / ring7.one()).is_err());
+    }
+}
@@ -0,0 +1,596 @@
+use super::discrete_log::pohlig_hellman;
+use super::mod_int::ModInt;
+use super::{IntegerMod, IntegerModRing};
+use crate::algebraic::ring::crt::crt;
+use crate::algebraic::ring::integer::ZZ;
+use crate::algebraic::ring::{EuclidDomain, Factor, UFD};
+use crate::algebraic::{One, Zero};
+use num::{Integer, Signed};
+
+fn inverse_mod(a: &ZZ, m: &ZZ) -> ZZ {
+    ZZ::mod_inverse(a.clone(), m.clone()).unwrap()
+}
+
+// 素数 p を法とする平方根 (Tonelli–Shanks)
+fn sqrt_prime(a: &ZZ, p: &ZZ) -> Option<ZZ> {
+    let a = a.mod_floor(p);
+    if a.is_zero() || p == &ZZ::from(2) {
+        return Some(a);
+    }
+    let pm1: ZZ = p - 1;
+    let half = &pm1 >> 1;
+    if !a.modpow(&half, p).is_one() {
+        return None;
+    }
+    // p - 1 = q 2^s
+    let s = pm1.trailing_zeros().unwrap();
+    let q = &pm1 >> s;
+    let mut z = ZZ::from(2);
+    while z.modpow(&half, p).is_one() {
+        z += 1;
+    }
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + 1) >> 1), p);
+    while !t.is_one() {
+        // t^(2^i) = 1 となる最小の i
+        let mut i = 0;
+        let mut t2 = t.clone();
+        while !t2.is_one() {
+            t2 = &t2 * &t2 % p;
+            i += 1;
+        }
+        let b = c.modpow(&(ZZ::one() << (m - i - 1)), p);
+        m = i;
+        c = &b * &b % p;
+        t = t * &c % p;
+        r = r * b % p;
+    }
+    Some(r)
+}
+
+// x^2 ≡ a (mod p^e) の解全体を {r + t * period | r ∈ roots} の形で返す
+fn sqrt_prime_power(a: &ZZ, p: &ZZ, e: u32) -> Option<(Vec<ZZ>, ZZ)> {
+    let pe = p.pow(e);
+    let a = a.mod_floor(&pe);
+    if a.is_zero() {
+        return Some((vec![ZZ::zero()], p.pow(e.div_ceil(2))));
+    }
+    // a = p^v u (u は単元) なら x = p^(v/2) y, y^2 ≡ u (mod p^(e-v))
+    let mut v = 0;
+    let mut u = a;
+    while u.is_multiple_of(p) {
+        u /= p;
+        v += 1;
+    }
+    if v % 2 == 1 {
+        return None;
+    }
+    let k = e - v;
+    let pk = p.pow(k);
+    let mut roots = if p == &ZZ::from(2) {
+        match k {
+            1 => vec![ZZ::one()],
+            2 if u.mod_floor(&4.into()).is_one() => vec![1.into(), 3.into()],
+            2 => return None,
+            _ if !u.mod_floor(&8.into()).is_one() => return None,
+            _ => {
+                let mut x = ZZ::one();
+                for j in 3..k {
+                    if !(&x * &x - &u).is_multiple_of(&(ZZ::one() << (j + 1))) {
+                        x += ZZ::one() << (j - 1);
+                    }
+                }
+                let half = ZZ::one() << (k - 1);
+                vec![
+                    x.clone(),
+                    &pk - &x,
+                    (&x + &half).mod_floor(&pk),
+                    (&pk - &x + &half).mod_floor(&pk),
+                ]
+            }
+        }
+    } else {
+        // Hensel 持ち上げ x ← x - (x^2 - u) / 2x
+        let mut x = sqrt_prime(&u, p)?;
+        let mut pj = p.clone();
+        for _ in 1..k {
+            pj *= p;
+            let inv = inverse_mod(&(&x * 2), &pj);
+            x = (&x - (&x * &x - &u) * inv).mod_floor(&pj);
+        }
+        vec![x.clone(), (&pk - &x).mod_floor(&pk)]
+    };
+    roots.sort();
+    roots.dedup();
+    let shift = p.pow(v / 2);
+    let roots = roots.into_iter().map(|r| r * &shift).collect();
+    Some((roots, pk * shift))
+}
+
+// x^(r^e) = a となる x を 1 つ (p - 1 の r-Sylow 部分群での離散対数で補正する)
+fn prime_power_root(a: &IntegerMod, r: &ZZ, e: u32) -> Option<IntegerMod> {
+    let ring = a.parent();
+    let m: ZZ = ring.order() - 1;
+    let mut t = 0;
+    let mut s = m.clone();
+    while s.is_multiple_of(r) {
+        s /= r;
+        t += 1;
+    }
+    let re = r.pow(e);
+    // x0 = a^(r^-e mod s) は r^e 乗すると a との差が r-Sylow 部分群に入る
+    let alpha = if s.is_one() {
+        ZZ::zero()
+    } else {
+        inverse_mod(&re, &s)
+    };
+    let x0 = a.pow(&alpha);
+    let err = x0.pow(&re) / a.clone();
+    let mut rho = ring.elem(2.into());
+    while rho.pow(&(&m / r)).is_one() {
+        rho += ring.one();
+    }
+    let c = rho.pow(&s);
+    let j = pohlig_hellman(&c, &err, &r.pow(t))?;
+    if !j.is_multiple_of(&re) {
+        return None;
+    }
+    Some(x0 * c.pow(&-(j / re)))
+}
+
+// 素数 p を法とする x^k = a の解を 1 つ (Adleman–Manders–Miller)
+fn nth_root_prime(a: &IntegerMod, k: &ZZ) -> Option<IntegerMod> {
+    if a.is_zero() {
+        return Some(a.clone());
+    }
+    let m = a.modulus() - 1;
+    let d = k.gcd(&m);
+    if !a.pow(&(&m / &d)).is_one() {
+        return None;
+    }
+    // x^d = a を d の素冪ごとに解いて合わせる
+    let (mut x, mut exponent) = (a.clone(), ZZ::one());
+    for Factor { p: r, e } in d.clone().factors() {
+        let y = prime_power_root(a, &r, e as u32)?;
+        let re = r.pow(e as u32);
+        let egcd = exponent.extended_gcd(&re);
+        x = x.pow(&egcd.y) * y.pow(&egcd.x);
+        exponent *= re;
+    }
+    let t = inverse_mod(&(k / &d), &(&m / &d));
+    Some(x.pow(&t))
+}
+
+// 素数 p を法とする x^k = a の解全体 (a は単元)
+fn all_nth_roots_prime(a: &IntegerMod, k: &ZZ) -> Vec<IntegerMod> {
+    let Some(x) = nth_root_prime(a, k) else {
+        return vec![];
+    };
+    // 1 の原始 d 乗根を掛けて全ての解を作る
+    let p = a.modulus();
+    let d = k.gcd(&(&p - 1));
+    let zeta = a
+        .parent()
+        .multiplicative_generator()
+        .unwrap()
+        .pow(&((&p - 1) / &d));
+    let mut roots = vec![x];
+    let mut i = ZZ::one();
+    while i < d {
+        let next = roots.last().unwrap().clone() * zeta.clone();
+        roots.push(next);
+        i += 1;
+    }
+    roots
+}
+
+// 単元 u について y^k ≡ u (mod p^f) の解を (r_i, j) で返す (y ≡ r_i (mod p^j) がちょうど解)
+fn unit_nth_roots_prime_power(u: &ZZ, k: &ZZ, p: &ZZ, f: u32, all: bool) -> Option<(Vec<ZZ>, u32)> {
+    // k = p^s k' (p と k' は互いに素)
+    let (mut s, mut k_) = (0, k.clone());
+    while k_.is_multiple_of(p) {
+        k_ /= p;
+        s += 1;
+    }
+    // j >= j0 なら y ≡ y' (mod p^j) から y^k ≡ y'^k (mod p^(j+s)) が従う
+    let two = p == &ZZ::from(2);
+    let m = f.min(if two { 2 } else { 1 });
+    let candidates: Vec<ZZ> = if two {
+        (1..1 << m).step_by(2).map(ZZ::from).collect()
+    } else {
+        let a = IntegerModRing::new(p.clone()).elem(u.clone());
+        if all {
+            all_nth_roots_prime(&a, k)
+        } else {
+            nth_root_prime(&a, k).into_iter().collect()
+        }
+        .into_iter()
+        .map(|x| x.value())
+        .collect()
+    };
+    let check = p.pow(f.min(m + s));
+    let mut roots: Vec<ZZ> = candidates
+        .into_iter()
+        .filter(|y| y.modpow(k, &check) == u.mod_floor(&check))
+        .collect();
+    if !all {
+        roots.truncate(1);
+    }
+    if roots.is_empty() {
+        return None;
+    }
+    // (y + t p^j)^k ≡ y^k + k' y^(k-1) t p^(j+s) (mod p^(j+s+1)) で t が一意に決まる
+    let (mut j, mut pj) = (m, p.pow(m));
+    while j + s < f {
+        let pjs = p.pow(j + s);
+        let next = &pjs * p;
+        for y in roots.iter_mut() {
+            let diff = (u - y.modpow(k, &next)).mod_floor(&next) / &pjs;
+            let df = &k_ * y.modpow(&(k - 1), p);
+            let t = (diff * inverse_mod(&df, p)).mod_floor(p);
+            *y += t * &pj;
+        }
+        j += 1;
+        pj *= p;
+    }
+    Some((roots, j))
+}
+
+// p^e を法とする x^k = a の解 (all でなければ高々 1 つ)
+fn nth_roots_prime_power(a: &ZZ, k: &ZZ, p: &ZZ, e: u32, all: bool) -> Vec<ZZ> {
+    let pe = p.pow(e);
+    let a = a.mod_floor(&pe);
+    // x^k ≡ 0 ⇔ p^ceil(e/k) | x
+    if a.is_zero() {
+        if !all {
+            return vec![ZZ::zero()];
+        }
+        let c = match u32::try_from(k) {
+            Ok(k) if k < e => e.div_ceil(k),
+            _ => 1,
+        };
+        let step = p.pow(c);
+        let mut roots = Vec::new();
+        let mut x = ZZ::zero();
+        while x < pe {
+            roots.push(x.clone());
+            x += &step;
+        }
+        return roots;
+    }
+    // a = p^v u (u は単元) なら x = p^(v/k) y, y^k ≡ u (mod p^(e-v))
+    let (mut v, mut u) = (0, a);
+    while u.is_multiple_of(p) {
+        u /= p;
+        v += 1;
+    }
+    if !ZZ::from(v).is_multiple_of(k) {
+        return vec![];
+    }
+    let w = u32::try_from(ZZ::from(v) / k).unwrap();
+    let Some((roots, j)) = unit_nth_roots_prime_power(&u, k, p, e - v, all) else {
+        return vec![];
+    };
+    let shift = p.pow(w);
+    if !all {
+        return vec![&roots[0] * shift];
+    }
+    // y は p^(e-w) を法として決まればよいので, p^j の倍数のずれを全て並べる
+    let (period, limit) = (p.pow(j), p.pow(e - w));
+    let mut result = Vec::new();
+    for r in roots {
+        let mut y = r;
+        while y < limit {
+            result.push(&y * &shift);
+            y += &period;
+        }
+    }
+    result.sort();
+    result
+}
+
+// 各素冪での解を CRT で合わせる
+fn combine(n: &ZZ, local: Vec<(Vec<ZZ>, ZZ)>) -> Vec<ZZ> {
+    let mut roots = vec![ZZ::zero()];
+    let mut modulus = ZZ::one();
+    for (rs, m) in local {
+        roots = roots
+            .iter()
+            .flat_map(|x| {
+                rs.iter()
+                    .map(|r| crt(&[(x.clone(), modulus.clone()), (r.clone(), m.clone())]))
+                    .map(|res| res.unwrap().0)
+            })
+            .collect();
+        modulus *= m;
+    }
+    roots.iter_mut().for_each(|x| *x = x.mod_floor(n));
+    roots.sort();
+    roots
+}
+
+// Z 上の x^k = a
+fn nth_roots_integer(a: &ZZ, k: &ZZ) -> Vec<ZZ> {
+    let even = k.is_even();
+    if a.is_negative() && even {
+        return vec![];
+    }
+    let x = match u32::try_from(k) {
+        Ok(k) => {
+            let x = a.abs().nth_root(k);
+            if x.pow(k) != a.abs() {
+                return vec![];
+            }
+            x
+        }
+        // k >= 2^32 なら |x| >= 2 の k 乗は表せないので |a| <= 1 のときだけ解がある
+        Err(_) if a.abs() <= ZZ::one() => a.abs(),
+        Err(_) => return vec![],
+    };
+    match (a.is_negative(), even && !x.is_zero()) {
+        (true, _) => vec![-x],
+        (false, true) => vec![-x.clone(), x],
+        (false, false) => vec![x],
+    }
+}
+
+impl IntegerMod {
+    pub fn is_square(&self) -> bool {
+        self.sqrt().is_some()
+    }
+
+    // 平方根を 1 つ
+    pub fn sqrt(&self) -> Option<Self> {
+        self.sqrt_impl(false).into_iter().next()
+    }
+
+    // 平方根全体 (昇順)
+    pub fn all_sqrt(&self) -> Vec<Self> {
+        self.sqrt_impl(true)
+    }
+
+    fn sqrt_impl(&self, all: bool) -> Vec<Self> {
+        let ring = self.parent();
+        if self.modulus.is_zero() {
+            return nth_roots_integer(&self.num, &2.into())
+                .into_iter()
+                .map(|x| ring.elem(x))
+                .collect();
+        }
+        let mut local = Vec::new();
+        for Factor { p, e } in self.modulus.clone().factors() {
+            let pe = p.pow(e as u32);
+            let (roots, period) = match sqrt_prime_power(&self.num, &p, e as u32) {
+                Some(x) => x,
+                None => return vec![],
+            };
+            if !all {
+                local.push((vec![roots[0].clone()], pe));
+                continue;
+            }
+            let mut expanded = Vec::new();
+            let mut base = ZZ::zero();
+            while base < pe {
+                expanded.extend(roots.iter().map(|r| r + &base));
+                base += &period;
+            }
+            local.push((expanded, pe));
+        }
+        combine(&self.modulus, local)
+            .into_iter()
+            .map(|x| ring.elem(x))
+            .collect()
+    }
+
+    // k 乗根を 1 つ (k >= 1)
+    pub fn nth_root(&self, k: &ZZ) -> Option<Self> {
+        self.nth_root_impl(k, false).into_iter().next()
+    }
+
+    // k 乗根全体 (昇順)
+    pub fn all_nth_roots(&self, k: &ZZ) -> Vec<Self> {
+        self.nth_root_impl(k, true)
+    }
+
+    fn nth_root_impl(&self, k: &ZZ, all: bool) -> Vec<Self> {
+        assert!(k.is_positive(), "k must be positive");
+        if k == &ZZ::from(2) {
+            return self.sqrt_impl(all);
+        }
+        let ring = self.parent();
+        if self.modulus.is_zero() {
+            return nth_roots_integer(&self.num, k)
+                .into_iter()
+                .map(|x| ring.elem(x))
+                .collect();
+        }
+        let mut local = Vec::new();
+        for Factor { p, e } in self.modulus.clone().factors() {
+            let roots = nth_roots_prime_power(&self.num, k, &p, e as u32, all);
+            if roots.is_empty() {
+                return vec![];
+            }
+            local.push((roots, p.pow(e as u32)));
+        }
+        combine(&self.modulus, local)
+            .into_iter()
+            .map(|x| ring.elem(x))
+            .collect()
+    }
+}
+
+impl ModInt {
+    pub fn sqrt(&self) -> Option<ModInt> {
+        let ring = IntegerModRing::new(ModInt::modulus().into());
+        let x = ring.elem(self.value().into()).sqrt()?;
+        Some(ModInt::from(i64::try_from(x.value()).unwrap()))
+    }
+
+    pub fn nth_root(&self, k: u64) -> Option<ModInt> {
+        let ring = IntegerModRing::new(ModInt::modulus().into());
+        let x = ring.elem(self.value().into()).nth_root(&k.into())?;
+        Some(ModInt::from(i64::try_from(x.value()).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer_mod::mod_int::set_mod_int;
+    use rand::Rng;
+
+    fn brute_force(a: &IntegerMod, k: u32) -> Vec<IntegerMod> {
+        a.parent()
+            .iter()
+            .filter(|x| x.pow(&k.into()) == *a)
+            .collect()
+    }
+
+    #[test]
+    fn small_moduli() {
+        for n in 1..80u64 {
+            let ring = IntegerModRing::new(n.into());
+            for a in ring.iter() {
+                let roots = a.all_sqrt();
+                assert_eq!(roots, brute_force(&a, 2), "sqrt {} mod {}", a, n);
+                assert_eq!(a.is_square(), !roots.is_empty());
+                if let Some(x) = a.sqrt() {
+                    assert_eq!(x.clone() * x, a);
+                }
+            }
+        }
+        for n in 1..40u64 {
+            let ring = IntegerModRing::new(n.into());
+            for a in ring.iter() {
+                for k in [3u32, 4, 6] {
+                    let roots = a.all_nth_roots(&k.into());
+                    assert_eq!(roots, brute_force(&a, k), "{}-th root {} mod {}", k, a, n);
+                    assert_eq!(a.nth_root(&k.into()).is_some(), !roots.is_empty());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn large_prime() {
+        let mut rng = rand::thread_rng();
+        for p in [998244353u64, 1_000_000_007] {
+            let ring = IntegerModRing::new(p.into());
+            for _ in 0..20 {
+                let x = ring.elem(rng.gen_range(1..p).into());
+                let a = x.clone() * x.clone();
+                let y = a.sqrt().unwrap();
+                assert_eq!(y.clone() * y, a);
+                assert_eq!(a.all_sqrt().len(), 2);
+
+                for k in [3u64, 7, 17, 1 << 20] {
+                    let a = x.pow(&k.into());
+                    let y = a.nth_root(&k.into()).unwrap();
+                    assert_eq!(y.pow(&k.into()), a);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn prime_power_and_composite() {
+        let n = ZZ::from(3u64.pow(10) * 1024 * 49);
+        let ring = IntegerModRing::new(n);
+        let x = ring.elem(123456.into());
+        let a = x.clone() * x.clone();
+        assert!(a.all_sqrt().contains(&x));
+        let a = x.pow(&5.into());
+        assert!(a.all_nth_roots(&5.into()).contains(&x));
+    }
+
+    #[test]
+    fn large_prime_power() {
+        // p | k や p | a の場合も素冪で全探索と一致する
+        for n in [256u64, 243, 625] {
+            let ring = IntegerModRing::new(n.into());
+            for k in [2u32, 3, 4, 5, 8, 9] {
+                // x^k ごとに x をまとめておく
+                let mut expected = vec![vec![]; n as usize];
+                for x in ring.iter() {
+                    let i = usize::try_from(x.pow(&k.into()).value()).unwrap();
+                    expected[i].push(x);
+                }
+                for (a, roots) in ring.iter().zip(expected) {
+                    assert_eq!(
+                        a.all_nth_roots(&k.into()),
+                        roots,
+                        "{}-th root {} mod {}",
+                        k,
+                        a,
+                        n
+                    );
+                }
+            }
+        }
+        let mut rng = rand::thread_rng();
+        for n in [ZZ::from(1u64 << 60), ZZ::from(10u64.pow(18))] {
+            let ring = IntegerModRing::new(n);
+            for _ in 0..20 {
+                let x = ring.elem(rng.gen::<u64>().into());
+                for k in [3u32, 4, 12, 25] {
+                    let a = x.pow(&k.into());
+                    let y = a.nth_root(&k.into()).unwrap();
+                    assert_eq!(y.pow(&k.into()), a);
+                }
+            }
+        }
+        // 2^60 での 3 乗は単元上で全単射, 4 乗根は 8 個
+        let ring = IntegerModRing::new(ZZ::from(1u64 << 60));
+        let x = ring.elem(12345.into());
+        assert_eq!(x.pow(&3.into()).all_nth_roots(&3.into()), vec![x.clone()]);
+        let roots = x.pow(&4.into()).all_nth_roots(&4.into());
+        assert_eq!(roots.len(), 8);
+        assert!(roots.contains(&x));
+        assert_eq!(ring.elem(2.into()).nth_root(&3.into()), None);
+        let a = ring.elem(ZZ::from(1u64 << 59));
+        assert_eq!(a.all_nth_roots(&3.into()), vec![]);
+        assert_eq!(ring.zero().nth_root(&3.into()), Some(ring.zero()));
+        // x^3 ≡ 0 (mod 2^22) ⇔ 2^8 | x
+        let ring = IntegerModRing::new(ZZ::from(1u64 << 22));
+        assert_eq!(ring.zero().all_nth_roots(&3.into()).len(), 1 << 14);
+        assert_eq!(ring.elem(2.into()).nth_root(&3.into()), None);
+    }
+
+    #[test]
+    fn mod_int() {
+        set_mod_int(998244353i64);
+        let a = ModInt::from(5i64).pow(2i64);
+        assert_eq!(a.sqrt().unwrap().pow(2i64).value(), 25);
+        assert_eq!(ModInt::from(3i64).sqrt().map(|x| x.value()), None);
+        let a = ModInt::from(12345i64).pow(23i64);
+        assert_eq!(a.nth_root(23).unwrap().pow(23i64).value(), a.value());
+    }
+
+    #[test]
+    fn integers() {
+        let ring = IntegerModRing::new(0.into());
+        assert_eq!(
+            ring.elem(49.into()).all_sqrt(),
+            vec![ring.elem((-7).into()), ring.elem(7.into())]
+        );
+        assert_eq!(
+            ring.elem((-27).into()).nth_root(&3.into()),
+            Some(ring.elem((-3).into()))
+        );
+        assert_eq!(ring.elem(2.into()).sqrt(), None);
+        // k が u32 に収まらなくても ±1, 0 だけは根を持つ
+        let k = ZZ::from(1u64 << 40) + 1;
+        assert_eq!(ring.elem(2.into()).nth_root(&k), None);
+        assert_eq!(
+            ring.elem((-1).into()).all_nth_roots(&k),
+            vec![ring.elem((-1).into())]
+        );
+        assert_eq!(
+            ring.one().all_nth_roots(&(k - 1)),
+            vec![ring.elem((-1).into()), ring.one()]
+        );
+        assert_eq!(ring.zero().nth_root(&ZZ::from(u64::MAX)), Some(ring.zero()));
+    }
+}
@@ -0,0 +1,343 @@
+use crate::algebraic::ring::convolution::{convolution_mod, Convolution};
+use crate::algebraic::{One, ScalarMul, ScalarPow, Sqrt, Zero};
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// 法をコンパイル時に決める剰余環の元
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StaticModInt<const M: u32>(u32);
+
+pub type ModInt998244353 = StaticModInt<998244353>;
+pub type ModInt1000000007 = StaticModInt<1_000_000_007>;
+
+impl<const M: u32> StaticModInt<M> {
+    #[inline]
+    pub const fn modulus() -> u32 {
+        M
+    }
+
+    // v < M を仮定する
+    #[inline]
+    pub const fn raw(v: u32) -> Self {
+        Self(v)
+    }
+
+    #[inline]
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    pub fn pow(&self, mut e: u64) -> Self {
+        let mut result = Self::one();
+        let mut cur = *self;
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= cur;
+            }
+            e >>= 1;
+            cur *= cur;
+        }
+        result
+    }
+
+    // 逆元 (M と互いに素でなければ None)
+    pub fn inv(&self) -> Option<Self> {
+        let (mut a, mut b) = (self.0 as i64, M as i64);
+        let (mut x, mut y) = (1i64, 0i64);
+        while b != 0 {
+            let q = a / b;
+            (a, b) = (b, a - q * b);
+            (x, y) = (y, x - q * y);
+        }
+        if a == 1 {
+            Some(Self(x.rem_euclid(M as i64) as u32))
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_from_primitive {
+    ($($t:ty)*) => {$(
+        impl<const M: u32> From<$t> for StaticModInt<M> {
+            fn from(v: $t) -> Self {
+                Self((v as i128).rem_euclid(M as i128) as u32)
+            }
+        }
+    )*};
+}
+impl_from_primitive!(i8 i16 i32 i64 i128 isize u8 u16 u32 u64 usize);
+
+impl<const M: u32> From<u128> for StaticModInt<M> {
+    fn from(v: u128) -> Self {
+        Self((v % M as u128) as u32)
+    }
+}
+
+impl<const M: u32> Zero for StaticModInt<M> {
+    fn zero() -> Self {
+        Self(0)
+    }
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const M: u32> One for StaticModInt<M> {
+    fn one() -> Self {
+        Self(1 % M)
+    }
+}
+
+impl<const M: u32> AddAssign for StaticModInt<M> {
+    fn add_assign(&mut self, rhs: Self) {
+        let sum = self.0 as u64 + rhs.0 as u64;
+        self.0 = if sum >= M as u64 { sum - M as u64 } else { sum } as u32;
+    }
+}
+
+impl<const M: u32> SubAssign for StaticModInt<M> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 = if self.0 >= rhs.0 {
+            self.0 - rhs.0
+        } else {
+            (self.0 as u64 + M as u64 - rhs.0 as u64) as u32
+        };
+    }
+}
+
+impl<const M: u32> MulAssign for StaticModInt<M> {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 = (self.0 as u64 * rhs.0 as u64 % M as u64) as u32;
+    }
+}
+
+impl<const M: u32> DivAssign for StaticModInt<M> {
+    fn div_assign(&mut self, rhs: Self) {
+        self.mul_assign(rhs.inv().expect("division by a non-invertible element"));
+    }
+}
+
+impl<const M: u32> Add for StaticModInt<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut tmp = self;
+        tmp += rhs;
+        tmp
+    }
+}
+
+impl<const M: u32> Neg for StaticModInt<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::zero() - self
+    }
+}
+
+impl<const M: u32> Sub for StaticModInt<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let mut tmp = self;
+        tmp -= rhs;
+        tmp
+    }
+}
+
+impl<const M: u32> Mul for StaticModInt<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut tmp = self;
+        tmp *= rhs;
+        tmp
+    }
+}
+
+impl<const M: u32> Div for StaticModInt<M> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let mut tmp = self;
+        tmp /= rhs;
+        tmp
+    }
+}
+
+impl<const M: u32> ScalarMul for StaticModInt<M> {
+    fn scalar_mul(&self, e: usize) -> Self {
+        *self * Self::from(e)
+    }
+}
+
+impl<const M: u32> ScalarPow for StaticModInt<M> {
+    fn scalar_pow(&self, e: usize) -> Self {
+        self.pow(e as u64)
+    }
+}
+
+// Tonelli-Shanks (M は素数とする, 合成数のときは平方根があっても None を返しうる)
+impl<const M: u32> Sqrt for StaticModInt<M> {
+    fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() || M == 2 {
+            return Some(*self);
+        }
+        let half = (M as u64 - 1) / 2;
+        if self.pow(half) != Self::one() {
+            return None;
+        }
+        let (mut q, mut s) = (M as u64 - 1, 0);
+        while q % 2 == 0 {
+            q /= 2;
+            s += 1;
+        }
+        let z = (2..M)
+            .map(Self::from)
+            .find(|z| z.pow(half) != Self::one())?;
+        let (mut c, mut t, mut r) = (z.pow(q), self.pow(q), self.pow(q.div_ceil(2)));
+        while t != Self::one() {
+            // t^(2^i) = 1 となる最小の i
+            let (mut i, mut u) = (0, t);
+            while u != Self::one() {
+                // M が素数なら i < s で止まる
+                if i + 1 >= s {
+                    return None;
+                }
+                u *= u;
+                i += 1;
+            }
+            let b = (0..s - i - 1).fold(c, |b, _| b * b);
+            (s, c) = (i, b * b);
+            t *= c;
+            r *= b;
+        }
+        (r * r == *self).then_some(r)
+    }
+}
+
+impl<const M: u32> Convolution for StaticModInt<M> {
+    fn convolution(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        let to_u32 = |v: &[Self]| v.iter().map(|x| x.0).collect::<Vec<_>>();
+        convolution_mod(&to_u32(lhs), &to_u32(rhs), M)
+            .into_iter()
+            .map(Self)
+            .collect()
+    }
+}
+
+impl<const M: u32> Display for StaticModInt<M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::polynomial::Polynomial;
+    use crate::algebraic::Field;
+    use rand::Rng;
+
+    fn random_ops<const M: u32>() {
+        let mut rng = rand::thread_rng();
+        let m = M as u64;
+        for _ in 0..10000 {
+            let x: u64 = rng.gen_range(0..m);
+            let y: u64 = rng.gen_range(1..m);
+            let (mx, my) = (StaticModInt::<M>::from(x), StaticModInt::<M>::from(y));
+            assert_eq!((mx + my).value() as u64, (x + y) % m);
+            assert_eq!((mx - my).value() as u64, (x + m - y) % m);
+            assert_eq!((mx * my).value() as u64, x * y % m);
+            assert_eq!(((mx / my) * my).value() as u64, x);
+            assert_eq!((-mx + mx).value(), 0);
+        }
+    }
+
+    #[test]
+    fn test_random_ops() {
+        random_ops::<998244353>();
+        random_ops::<1_000_000_007>();
+        random_ops::<1_000_000_009>();
+    }
+
+    #[test]
+    fn from_primitives() {
+        assert_eq!(ModInt1000000007::from(-1i64).value(), 1_000_000_006);
+        assert_eq!(ModInt1000000007::from(1_000_000_007u64 * 10).value(), 0);
+        assert_eq!(
+            ModInt998244353::from(u128::MAX).value(),
+            (u128::MAX % 998244353) as u32
+        );
+        assert_eq!(StaticModInt::<6>::from(4).inv(), None);
+        assert_eq!(StaticModInt::<1>::one().value(), 0);
+    }
+
+    #[test]
+    fn sqrt() {
+        for x in 0..1000u32 {
+            let a = ModInt998244353::from(x);
+            let is_square = a.is_zero() || a.pow((998244353 - 1) / 2).is_one();
+            match a.sqrt() {
+                Some(r) => assert_eq!(r * r, a),
+                None => assert!(!is_square),
+            }
+            assert_eq!(a.sqrt().is_some(), is_square);
+        }
+        for x in 0..13u32 {
+            let a = StaticModInt::<13>::from(x);
+            let expected = (0..13u32).any(|r| StaticModInt::<13>::from(r * r) == a);
+            assert_eq!(a.sqrt().map(|r| r * r == a), expected.then_some(true));
+        }
+        // 合成数の法 (101 * 9901) でも停止し, 返す値は平方根になっている
+        for x in 0..1000u32 {
+            let a = StaticModInt::<1_000_001>::from(x);
+            if let Some(r) = a.sqrt() {
+                assert_eq!(r * r, a);
+            }
+        }
+    }
+
+    fn sum_of_inverses<T: Field + Copy + From<usize>>(n: usize) -> T {
+        let mut sum = T::zero();
+        for i in 1..=n {
+            sum += T::one() / T::from(i);
+        }
+        sum
+    }
+
+    #[test]
+    fn generic_field() {
+        let s: ModInt998244353 = sum_of_inverses(10);
+        assert_eq!(s * ModInt998244353::from(2520), ModInt998244353::from(7381));
+    }
+
+    #[test]
+    fn fps_mul() {
+        let mut rng = rand::thread_rng();
+        fn check<const M: u32>(a: Vec<StaticModInt<M>>, b: Vec<StaticModInt<M>>) {
+            let mut expected = vec![StaticModInt::<M>::zero(); a.len() + b.len() - 1];
+            for (i, &x) in a.iter().enumerate() {
+                for (j, &y) in b.iter().enumerate() {
+                    expected[i + j] += x * y;
+                }
+            }
+            assert_eq!(
+                (Polynomial::from(a) * Polynomial::from(b)).coeff(),
+                expected
+            );
+        }
+        check::<998244353>(
+            (0..500)
+                .map(|_| rng.gen_range(1..998244353).into())
+                .collect(),
+            (0..700)
+                .map(|_| rng.gen_range(1..998244353).into())
+                .collect(),
+        );
+        check::<1_000_000_007>(
+            (0..500)
+                .map(|_| rng.gen_range(1..1_000_000_007).into())
+                .collect(),
+            (0..700)
+                .map(|_| rng.gen_range(1..1_000_000_007).into())
+                .collect(),
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use super::{IntegerMod, IntegerModRing};
+use crate::algebraic::ring::integer::arithmetic::EulerPhi;
+use crate::algebraic::ring::integer::{CarmichaelLambda, ZZ};
+use crate::algebraic::ring::{Factor, UFD};
+use crate::algebraic::{One, Zero};
+use num::Integer;
+
+// 単元群 (Z/nZ)^× の構造 (n > 0 を仮定する)
+impl IntegerModRing {
+    // Euler の phi 関数
+    pub fn unit_group_order(&self) -> ZZ {
+        assert!(!self.order.is_zero(), "the unit group of Z is {{1, -1}}");
+        self.order.clone().euler_phi()
+    }
+
+    // 単元群の指数 (Carmichael の lambda 関数)
+    pub fn unit_group_exponent(&self) -> ZZ {
+        self.order.clone().carmichael_lambda()
+    }
+
+    // 単元を小さい順に遅延列挙する
+    pub fn units(&self) -> impl Iterator<Item = IntegerMod> + '_ {
+        let bound = if self.order.is_zero() { 2 } else { usize::MAX };
+        self.iter()
+            .filter(|x| x.num.gcd(&self.order).is_one())
+            .take(bound)
+    }
+
+    pub fn list_of_elements_of_multiplicative_group(&self) -> Vec<ZZ> {
+        self.units().map(|x| x.num).collect()
+    }
+
+    // 原始根が存在するのは n = 1, 2, 4, p^k, 2p^k のとき (Z には原始根を考えないので false)
+    pub fn multiplicative_group_is_cyclic(&self) -> bool {
+        let mut n = self.order();
+        if n.is_zero() {
+            return false;
+        }
+        if n < 8.into() {
+            return true;
+        }
+        if n.is_multiple_of(&4.into()) {
+            return false;
+        }
+        if n.is_even() {
+            n /= 2;
+        }
+        n.factors().len() == 1
+    }
+
+    // 原始根 (単元群が巡回群でなければ None)
+    pub fn multiplicative_generator(&self) -> Option<IntegerMod> {
+        if !self.multiplicative_group_is_cyclic() {
+            return None;
+        }
+        let phi = self.unit_group_order();
+        let primes: Vec<ZZ> = phi.clone().factors().into_iter().map(|f| f.p).collect();
+        self.units()
+            .find(|g| primes.iter().all(|q| !g.pow(&(&phi / q)).num.is_one()))
+    }
+
+    // 巡回群の直積 C_{d_1} × C_{d_2} × ... としての構造 (生成元と位数の組)
+    pub fn unit_group_structure(&self) -> Vec<(IntegerMod, ZZ)> {
+        let n = self.order();
+        let mut res = Vec::new();
+        for Factor { p, e } in n.clone().factors() {
+            let q = p.pow(e as u32);
+            // 局所的な生成元 g (mod p^e) を, 他の素因子では 1 になるように持ち上げる
+            let rest = &n / &q;
+            let rest_inv = IntegerModRing::new(q.clone())
+                .elem(rest.clone())
+                .pow(&(-1).into());
+            let lift = |g: ZZ| self.elem(ZZ::one() + (g - 1) * &rest * rest_inv.value());
+            if p == 2.into() {
+                match e {
+                    1 => {}
+                    2 => res.push((lift(3.into()), 2.into())),
+                    _ => {
+                        res.push((lift(&q - 1), 2.into()));
+                        res.push((lift(5.into()), ZZ::one() << (e - 2)));
+                    }
+                }
+            } else {
+                let local = IntegerModRing::new(q.clone());
+                let g = local.multiplicative_generator().unwrap();
+                res.push((lift(g.num), local.unit_group_order()));
+            }
+        }
+        res
+    }
+}
+
+impl IntegerMod {
+    pub fn is_unit(&self) -> bool {
+        self.try_inverse().is_ok()
+    }
+
+    // 乗法的位数 (単元でなければ None)
+    pub fn multiplicative_order(&self) -> Option<ZZ> {
+        if !self.is_unit() || self.modulus.is_zero() {
+            return None;
+        }
+        let mut order = self.modulus.clone().carmichael_lambda();
+        for Factor { p, e } in order.clone().factors() {
+            for _ in 0..e {
+                let candidate = &order / &p;
+                if self.pow(&candidate) == self.parent().one() {
+                    order = candidate;
+                } else {
+                    break;
+                }
+            }
+        }
+        Some(order)
+    }
+
+    pub fn is_primitive_root(&self) -> bool {
+        !self.modulus.is_zero()
+            && self.multiplicative_order() == Some(self.parent().unit_group_order())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn phi_naive(n: u64) -> u64 {
+        (1..=n).filter(|&i| i.gcd(&n) == 1).count() as u64
+    }
+
+    #[test]
+    fn unit_group_order() {
+        for n in 1..200u64 {
+            let ring = IntegerModRing::new(n.into());
+            assert_eq!(ring.unit_group_order(), phi_naive(n).into());
+            assert_eq!(ring.units().count() as u64, phi_naive(n));
+            for x in ring.units() {
+                let order = x.multiplicative_order().unwrap();
+                let mut y = x.clone();
+                let mut k = ZZ::one();
+                while y != ring.one() {
+                    y *= x.clone();
+                    k += 1;
+                }
+                assert_eq!(order, k);
+            }
+        }
+        let ring = IntegerModRing::new(12.into());
+        assert_eq!(ring.elem(2.into()).multiplicative_order(), None);
+    }
+
+    #[test]
+    fn primitive_roots() {
+        for n in 1..300u64 {
+            let ring = IntegerModRing::new(n.into());
+            match ring.multiplicative_generator() {
+                Some(g) => assert!(g.is_primitive_root(), "n = {}", n),
+                None => assert!(!ring.multiplicative_group_is_cyclic()),
+            }
+        }
+        let ring = IntegerModRing::new(998244353.into());
+        assert_eq!(ring.multiplicative_generator().unwrap().value(), 3.into());
+        assert!(IntegerModRing::new(50.into()).multiplicative_group_is_cyclic());
+        assert!(!IntegerModRing::new(15.into()).multiplicative_group_is_cyclic());
+        assert!(IntegerModRing::new(1.into())
+            .multiplicative_generator()
+            .is_some());
+        let integers = IntegerModRing::new(0.into());
+        assert!(!integers.multiplicative_group_is_cyclic());
+        assert_eq!(integers.multiplicative_generator(), None);
+    }
+
+    #[test]
+    fn structure_generates_unit_group() {
+        for n in [8u64, 15, 16, 360, 2 * 3 * 3 * 5 * 7, 1 << 7] {
+            let ring = IntegerModRing::new(n.into());
+            let structure = ring.unit_group_structure();
+            let mut group: HashSet<ZZ> = [ZZ::one() % n].into();
+            let mut size = ZZ::one();
+            for (g, order) in &structure {
+                assert_eq!(g.multiplicative_order().as_ref(), Some(order));
+                size *= order;
+                let mut next = HashSet::new();
+                for x in &group {
+                    let mut y = ring.elem(x.clone());
+                    for _ in 0..u64::try_from(order).unwrap() {
+                        next.insert(y.value());
+                        y *= g.clone();
+                    }
+                }
+                group = next;
+            }
+            assert_eq!(size, ring.unit_group_order());
+            let units: HashSet<ZZ> = ring
+                .list_of_elements_of_multiplicative_group()
+                .into_iter()
+                .collect();
+            assert_eq!(group, units);
+        }
+    }
+
+    #[test]
+    fn big_modulus_units() {
+        let n = (ZZ::one() << 70) + 1;
+        let ring = IntegerModRing::new(n);
+        let units: Vec<ZZ> = ring.units().take(4).map(|x| x.value()).collect();
+        assert_eq!(units, [1, 2, 3, 4].map(ZZ::from));
+        assert_eq!(IntegerModRing::new(0.into()).units().count(), 2);
+    }
+}
@@ -0,0 +1,281 @@
+use super::Matrix;
+use crate::algebraic::{Field, Ring};
+use std::ops::Div;
+
+impl<T: Field + Clone> Matrix<T> {
+    // 掃き出して簡約階段形にし, (ピボットの列, 正方行列なら行列式) を返す
+    fn eliminate(&mut self) -> (Vec<usize>, T) {
+        let mut pivots = Vec::new();
+        let mut det = T::one();
+        for c in 0..self.cols {
+            let r = pivots.len();
+            if r == self.rows {
+                break;
+            }
+            let Some(p) = (r..self.rows).find(|&i| !self[(i, c)].is_zero()) else {
+                det = T::zero();
+                continue;
+            };
+            if p != r {
+                self.swap_rows(p, r);
+                det = -det;
+            }
+            let pivot = self[(r, c)].clone();
+            let inv = T::one() / pivot.clone();
+            det *= pivot;
+            for j in c..self.cols {
+                self[(r, j)] *= inv.clone();
+            }
+            for i in 0..self.rows {
+                if i == r || self[(i, c)].is_zero() {
+                    continue;
+                }
+                let f = self[(i, c)].clone();
+                for j in c..self.cols {
+                    let sub = f.clone() * self[(r, j)].clone();
+                    self[(i, j)] -= sub;
+                }
+            }
+            pivots.push(c);
+        }
+        if pivots.len() < self.rows {
+            det = T::zero();
+        }
+        (pivots, det)
+    }
+
+    // 簡約階段形とピボットの列
+    pub fn row_echelon(&self) -> (Self, Vec<usize>) {
+        let mut a = self.clone();
+        let (pivots, _) = a.eliminate();
+        (a, pivots)
+    }
+
+    pub fn rank(&self) -> usize {
+        self.row_echelon().1.len()
+    }
+
+    pub fn determinant(&self) -> T {
+        assert!(self.is_square(), "the matrix is not square");
+        self.clone().eliminate().1
+    }
+
+    // 正則でなければ None
+    pub fn inverse(&self) -> Option<Self> {
+        assert!(self.is_square(), "the matrix is not square");
+        let n = self.rows;
+        // [A | I] を掃き出す
+        let mut a = Matrix::zero(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                a[(i, j)] = self[(i, j)].clone();
+            }
+            a[(i, n + i)] = T::one();
+        }
+        let (pivots, _) = a.eliminate();
+        if pivots.len() < n || pivots.iter().any(|&c| c >= n) {
+            return None;
+        }
+        let data = (0..n).flat_map(|i| a.row(i)[n..].to_vec()).collect();
+        Some(Matrix::new(n, n, data))
+    }
+
+    // A x = b の解の 1 つと A x = 0 の解空間の基底 (解がなければ None)
+    pub fn solve(&self, b: &[T]) -> Option<(Vec<T>, Vec<Vec<T>>)> {
+        assert_eq!(self.rows, b.len(), "number of rows is not matched");
+        let (n, m) = (self.rows, self.cols);
+        // [A | b] を掃き出す
+        let mut a = Matrix::zero(n, m + 1);
+        for i in 0..n {
+            for j in 0..m {
+                a[(i, j)] = self[(i, j)].clone();
+            }
+            a[(i, m)] = b[i].clone();
+        }
+        let (pivots, _) = a.eliminate();
+        if pivots.last() == Some(&m) {
+            return None;
+        }
+        let mut x = vec![T::zero(); m];
+        for (i, &c) in pivots.iter().enumerate() {
+            x[c] = a[(i, m)].clone();
+        }
+        // 自由変数を 1 つだけ 1 にする
+        let mut is_pivot = vec![false; m];
+        for &c in &pivots {
+            is_pivot[c] = true;
+        }
+        let kernel = (0..m)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![T::zero(); m];
+                v[f] = T::one();
+                for (i, &c) in pivots.iter().enumerate() {
+                    v[c] = -a[(i, f)].clone();
+                }
+                v
+            })
+            .collect();
+        Some((x, kernel))
+    }
+}
+
+impl<T: Ring + Div<Output = T> + Clone> Matrix<T> {
+    // 行列式 (Bareiss, 整域で除算は割り切れる)
+    pub fn bareiss_determinant(&self) -> T {
+        assert!(self.is_square(), "the matrix is not square");
+        let n = self.rows;
+        if n == 0 {
+            return T::one();
+        }
+        let mut a = self.clone();
+        let mut negative = false;
+        let mut prev = T::one();
+        for k in 0..n - 1 {
+            if a[(k, k)].is_zero() {
+                let Some(p) = (k + 1..n).find(|&i| !a[(i, k)].is_zero()) else {
+                    return T::zero();
+                };
+                a.swap_rows(p, k);
+                negative = !negative;
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let v = a[(i, j)].clone() * a[(k, k)].clone()
+                        - a[(i, k)].clone() * a[(k, j)].clone();
+                    a[(i, j)] = v / prev.clone();
+                }
+            }
+            prev = a[(k, k)].clone();
+        }
+        let det = a[(n - 1, n - 1)].clone();
+        if negative {
+            -det
+        } else {
+            det
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::{QQ, ZZ};
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+    use crate::algebraic::Zero;
+    use rand::Rng;
+
+    type Mint = ModInt998244353;
+
+    fn random_matrix(rng: &mut rand::rngs::ThreadRng, n: usize, m: usize) -> Matrix<Mint> {
+        Matrix::new(
+            n,
+            m,
+            (0..n * m).map(|_| Mint::from(rng.gen::<u32>())).collect(),
+        )
+    }
+
+    fn qq(rows: &[&[i64]]) -> Matrix<QQ> {
+        Matrix::from(
+            rows.iter()
+                .map(|row| row.iter().map(|&x| QQ::from_integer(x.into())).collect())
+                .collect::<Vec<Vec<_>>>(),
+        )
+    }
+
+    fn column<T: Field + Clone>(v: &[T]) -> Matrix<T> {
+        Matrix::new(v.len(), 1, v.to_vec())
+    }
+
+    #[test]
+    fn determinant_and_inverse() {
+        let a = qq(&[&[2, 1, 1], &[1, 3, 2], &[1, 0, 0]]);
+        assert_eq!(a.determinant(), QQ::from_integer((-1).into()));
+        let inv = a.inverse().unwrap();
+        assert_eq!(inv.clone() * a.clone(), Matrix::identity(3));
+        assert_eq!(a * inv, Matrix::identity(3));
+        let singular = qq(&[&[1, 2], &[2, 4]]);
+        assert!(singular.determinant().is_zero());
+        assert_eq!(singular.inverse(), None);
+        assert_eq!(singular.rank(), 1);
+        // 先頭の成分が 0 でも行を入れ替える
+        let b = qq(&[&[0, 1], &[1, 0]]);
+        assert_eq!(b.determinant(), QQ::from_integer((-1).into()));
+        assert_eq!(b.inverse(), Some(b));
+
+        let mut rng = rand::thread_rng();
+        for n in [1, 5, 30] {
+            let a = random_matrix(&mut rng, n, n);
+            let b = random_matrix(&mut rng, n, n);
+            assert_eq!(
+                (a.clone() * b.clone()).determinant(),
+                a.determinant() * b.determinant()
+            );
+            assert_eq!(a.clone() * a.inverse().unwrap(), Matrix::identity(n));
+        }
+    }
+
+    #[test]
+    fn bareiss() {
+        let zz = |rows: &[&[i64]]| {
+            Matrix::from(
+                rows.iter()
+                    .map(|row| row.iter().map(|&x| ZZ::from(x)).collect())
+                    .collect::<Vec<Vec<_>>>(),
+            )
+        };
+        assert_eq!(
+            zz(&[&[2, 1, 1], &[1, 3, 2], &[1, 0, 0]]).bareiss_determinant(),
+            ZZ::from(-1)
+        );
+        assert_eq!(zz(&[&[0, 1], &[1, 0]]).bareiss_determinant(), ZZ::from(-1));
+        assert_eq!(zz(&[&[1, 2], &[2, 4]]).bareiss_determinant(), ZZ::zero());
+        assert_eq!(Matrix::<ZZ>::zero(0, 0).bareiss_determinant(), ZZ::from(1));
+        let mut rng = rand::thread_rng();
+        for n in [1, 4, 12] {
+            let rows: Vec<Vec<i64>> = (0..n)
+                .map(|_| (0..n).map(|_| rng.gen_range(-9..10)).collect())
+                .collect();
+            let rows: Vec<&[i64]> = rows.iter().map(|row| row.as_slice()).collect();
+            let det = zz(&rows).bareiss_determinant();
+            assert_eq!(QQ::from_integer(det), qq(&rows).determinant());
+        }
+    }
+
+    #[test]
+    fn linear_system() {
+        // x + 2y + 3z = 6, 2x + 4y + 6z = 12, x + y + z = 3
+        let a = qq(&[&[1, 2, 3], &[2, 4, 6], &[1, 1, 1]]);
+        let b: Vec<QQ> = [6, 12, 3]
+            .iter()
+            .map(|&x| QQ::from_integer(x.into()))
+            .collect();
+        let (x, kernel) = a.solve(&b).unwrap();
+        assert_eq!(a.clone() * column(&x), column(&b));
+        assert_eq!(kernel.len(), 1);
+        assert_eq!(a.clone() * column(&kernel[0]), Matrix::zero(3, 1));
+        let (r, pivots) = a.row_echelon();
+        assert_eq!(pivots, vec![0, 1]);
+        assert_eq!(r, qq(&[&[1, 0, -1], &[0, 1, 2], &[0, 0, 0]]));
+        // 解なし
+        let c: Vec<QQ> = [6, 13, 3]
+            .iter()
+            .map(|&x| QQ::from_integer(x.into()))
+            .collect();
+        assert_eq!(a.solve(&c), None);
+
+        let mut rng = rand::thread_rng();
+        for (n, m, rank) in [(5, 8, 3), (8, 5, 5), (10, 10, 7)] {
+            let a = random_matrix(&mut rng, n, rank) * random_matrix(&mut rng, rank, m);
+            assert_eq!(a.rank(), rank);
+            let x0: Vec<Mint> = (0..m).map(|_| Mint::from(rng.gen::<u32>())).collect();
+            let b = (a.clone() * column(&x0)).transpose().row(0).to_vec();
+            let (x, kernel) = a.solve(&b).unwrap();
+            assert_eq!(a.clone() * column(&x), column(&b));
+            assert_eq!(kernel.len(), m - rank);
+            for v in &kernel {
+                assert_eq!(a.clone() * column(v), Matrix::zero(n, 1));
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use crate::algebraic::{Ring, Semiring};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+pub mod gauss;
+
+// 行優先で平らに並べた行列
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Matrix<T: Semiring + Clone> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+// row: 行 column: 列
+impl<T: Semiring + Clone> Matrix<T> {
+    pub fn new(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), rows * cols, "the matrix is not aligned");
+        Matrix { rows, cols, data }
+    }
+
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Matrix::new(rows, cols, vec![T::zero(); rows * cols])
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut ret = Matrix::zero(n, n);
+        for i in 0..n {
+            ret[(i, i)] = T::one();
+        }
+        ret
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn row(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
+    }
+
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        if i == j {
+            return;
+        }
+        let (i, j) = (i.min(j), i.max(j));
+        let (upper, lower) = self.data.split_at_mut(j * self.cols);
+        upper[i * self.cols..(i + 1) * self.cols].swap_with_slice(&mut lower[..self.cols]);
+    }
+
+    pub fn transpose(&self) -> Self {
+        let data = (0..self.cols)
+            .flat_map(|j| (0..self.rows).map(move |i| self[(i, j)].clone()))
+            .collect();
+        Matrix::new(self.cols, self.rows, data)
+    }
+
+    // 繰り返し二乗法で self^k
+    pub fn pow(&self, mut k: u64) -> Self {
+        assert!(self.is_square(), "the matrix is not square");
+        let mut ret = Matrix::identity(self.rows);
+        let mut base = self.clone();
+        while k > 0 {
+            if k & 1 == 1 {
+                ret *= base.clone();
+            }
+            k >>= 1;
+            if k > 0 {
+                base = base.clone() * base;
+            }
+        }
+        ret
+    }
+
+    fn check_size(&self, rhs: &Self) {
+        assert_eq!(self.rows, rhs.rows, "number of rows is not matched");
+        assert_eq!(self.cols, rhs.cols, "number of columns is not matched");
+    }
+}
+
+// 各行の長さが揃っていなければ panic
+impl<T: Semiring + Clone> From<Vec<Vec<T>>> for Matrix<T> {
+    fn from(nums: Vec<Vec<T>>) -> Self {
+        let rows = nums.len();
+        let cols = nums.first().map_or(0, |row| row.len());
+        assert!(
+            nums.iter().all(|row| row.len() == cols),
+            "the matrix is not aligned"
+        );
+        Matrix::new(rows, cols, nums.into_iter().flatten().collect())
+    }
+}
+
+impl<T: Semiring + Clone> AddAssign for Matrix<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.check_size(&rhs);
+        for (x, y) in self.data.iter_mut().zip(rhs.data) {
+            *x += y;
+        }
+    }
+}
+
+impl<T: Semiring + Clone> Add for Matrix<T> {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<T: Ring + Clone> SubAssign for Matrix<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.check_size(&rhs);
+        for (x, y) in self.data.iter_mut().zip(rhs.data) {
+            *x -= y;
+        }
+    }
+}
+
+impl<T: Ring + Clone> Sub for Matrix<T> {
+    type Output = Self;
+    fn sub(mut self, rhs: Self) -> Self {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T: Ring + Clone> Neg for Matrix<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Matrix::new(
+            self.rows,
+            self.cols,
+            self.data.into_iter().map(|x| -x).collect(),
+        )
+    }
+}
+
+impl<T: Semiring + Clone> Mul for Matrix<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        assert_eq!(
+            self.cols, rhs.rows,
+            "number of columns and rows are not matched"
+        );
+        let mut ret = Matrix::zero(self.rows, rhs.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = &self[(i, k)];
+                for j in 0..rhs.cols {
+                    ret[(i, j)] += a.clone() * rhs[(k, j)].clone();
+                }
+            }
+        }
+        ret
+    }
+}
+
+impl<T: Semiring + Clone> MulAssign for Matrix<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = std::mem::replace(self, Matrix::zero(0, 0)) * rhs;
+    }
+}
+
+impl<T: Semiring + Clone> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({}, {}) out of bounds for {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        &self.data[i * self.cols + j]
+    }
+}
+
+impl<T: Semiring + Clone> IndexMut<(usize, usize)> for Matrix<T> {
+    #[inline]
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(
+            i < self.rows && j < self.cols,
+            "index ({}, {}) out of bounds for {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+// 各行を空白区切りで改行して並べる
+impl<T: Semiring + Clone + Display> Display for Matrix<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for i in 0..self.rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for (j, x) in self.row(i).iter().enumerate() {
+                if j > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{}", x)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+
+    #[test]
+    fn arithmetic() {
+        let a = Matrix::from(vec![vec![1i64, 2, 3], vec![4, 5, 6]]);
+        let b = Matrix::from(vec![vec![1i64, 0], vec![-1, 2], vec![0, 1]]);
+        assert_eq!((a.nrows(), a.ncols()), (2, 3));
+        assert_eq!(a[(1, 2)], 6);
+        // 列がはみ出たら次の行を読まずに panic する
+        assert!(std::panic::catch_unwind(|| a[(0, 3)]).is_err());
+        assert!(std::panic::catch_unwind(|| a.clone()[(2, 0)] = 0).is_err());
+        assert_eq!(a.row(1), &[4, 5, 6]);
+        assert_eq!(
+            a.clone() * b.clone(),
+            Matrix::from(vec![vec![-1, 7], vec![-1, 16]])
+        );
+        assert_eq!(
+            b.clone() * a.clone(),
+            Matrix::from(vec![vec![1, 2, 3], vec![7, 8, 9], vec![4, 5, 6]])
+        );
+        assert_eq!(a.transpose().transpose(), a);
+        assert_eq!(
+            a.transpose() + b.clone(),
+            Matrix::new(3, 2, vec![2, 4, 1, 7, 3, 7])
+        );
+        assert_eq!(a.clone() - a.clone(), Matrix::zero(2, 3));
+        assert_eq!(-a.clone() + a.clone(), Matrix::zero(2, 3));
+        assert_eq!(Matrix::identity(2) * a.clone(), a);
+        assert_eq!(a.clone() * Matrix::identity(3), a);
+        let mut c = Matrix::identity(2);
+        c *= Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+        c *= Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+        assert_eq!(c, Matrix::from(vec![vec![2, 1], vec![1, 1]]));
+        let fib = Matrix::from(vec![vec![1, 1], vec![1, 0]]);
+        assert_eq!(fib.pow(0), Matrix::identity(2));
+        assert_eq!(fib.pow(10)[(0, 1)], 55);
+        assert_eq!(format!("{}", a), "1 2 3\n4 5 6");
+
+        type Mint = ModInt998244353;
+        let m = Matrix::from(vec![vec![Mint::from(998244352), Mint::from(2)]]);
+        assert_eq!(
+            m.clone() * m.transpose(),
+            Matrix::from(vec![vec![Mint::from(5)]])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched() {
+        let _ = Matrix::<i64>::identity(2) * Matrix::identity(3);
+    }
+}
@@ -1,3 +1,5 @@
+pub mod convolution;
+pub mod crt;
 pub mod finite_field;
 pub mod integer;
 pub mod integer_mod;
@@ -5,6 +7,8 @@ pub mod matrix;
 pub mod polynomial;
 pub mod tropical;
 
+use num::{One, Zero};
+
 #[derive(Debug, PartialEq, PartialOrd)]
 pub struct Factor<T> {
     pub p: T,
@@ -16,8 +20,25 @@ pub trait UFD {
         Self: Sized;
 }
 
-pub trait EuclidDomain {
+pub trait EuclidDomain: Sized {
+    // 非負の最大公約数 (符号付き整数で gcd = |MIN| となり表せないときは panic する)
     fn gcd(lhs: Self, rhs: Self) -> Self;
-    fn xgcd(lhs: Self, rhs: Self, x: &mut Self, y: &mut Self) -> Self;
+    // (g, x, y) で lhs x + rhs y = g (rhs != 0 なら 0 <= x < |rhs| / g, rhs = 0 なら x = sgn(lhs))
+    fn xgcd(lhs: Self, rhs: Self) -> (Self, Self, Self);
     fn lcm(lhs: Self, rhs: Self) -> Self;
+    // a x ≡ 1 (mod m) となる 0 <= x < m (m > 0)
+    fn mod_inverse(a: Self, m: Self) -> Option<Self>;
+
+    fn gcd_all<I: IntoIterator<Item = Self>>(iter: I) -> Self
+    where
+        Self: Zero,
+    {
+        iter.into_iter().fold(Self::zero(), Self::gcd)
+    }
+    fn lcm_all<I: IntoIterator<Item = Self>>(iter: I) -> Self
+    where
+        Self: One,
+    {
+        iter.into_iter().fold(Self::one(), Self::lcm)
+    }
 }
@@ -0,0 +1,251 @@
+use super::{Analysis, Polynomial, PolynomialError, PowerSeries};
+use crate::algebraic::Zero;
+
+// これより小さいと素朴な方法の方が速い
+const NAIVE_DIVISION_THRESHOLD: usize = 32;
+const NAIVE_EVALUATION_THRESHOLD: usize = 16;
+
+impl<T: Analysis> Polynomial<T> {
+    // 商と余り (rev(rhs) の逆元を Newton 法で求めて O(n log n))
+    pub(super) fn fast_div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialError> {
+        let (n, m) = (self.0.len(), rhs.0.len());
+        if m == 0 || n < m || (n - m + 1).min(m) <= NAIVE_DIVISION_THRESHOLD {
+            return self.div_rem(rhs);
+        }
+        // rev(self) = rev(q) rev(rhs) mod x^(n - m + 1)
+        let k = n - m + 1;
+        let rev = |v: &[T]| Polynomial::from(v.iter().rev().copied().collect::<Vec<_>>());
+        let inv = PowerSeries::from_polynomial(&rev(&rhs.0), k).inv().unwrap();
+        let q = PowerSeries::from_polynomial(&rev(&self.0), k) * inv;
+        let q = rev(&q.coeff());
+        let r = self.clone() - q.clone() * rhs.clone();
+        Ok((q, r))
+    }
+
+    // 各点での値 (部分積木で O(n log^2 n))
+    pub fn multipoint_evaluate(&self, points: &[T]) -> Vec<T> {
+        if points.is_empty() {
+            return Vec::new();
+        }
+        let tree = SubproductTree::new(points);
+        let mut values = vec![T::zero(); points.len()];
+        tree.evaluate(self.clone(), 0, 0, points.len(), &mut values);
+        values
+    }
+
+    // (x_i, y_i) を通る次数 n 未満の多項式 (x_i が重複すれば Err)
+    pub fn interpolate(pairs: &[(T, T)]) -> Result<Self, PolynomialError> {
+        if pairs.is_empty() {
+            return Ok(Polynomial::zero());
+        }
+        let points: Vec<T> = pairs.iter().map(|&(x, _)| x).collect();
+        let tree = SubproductTree::new(&points);
+        // f = Σ y_i / M'(x_i) M(x) / (x - x_i) (M = Π (x - x_i))
+        let mut weights = vec![T::zero(); points.len()];
+        tree.evaluate(tree.nodes[0].diff(), 0, 0, points.len(), &mut weights);
+        if weights.iter().any(|w| w.is_zero()) {
+            return Err(PolynomialError::DuplicatePoints);
+        }
+        for (w, &(_, y)) in weights.iter_mut().zip(pairs) {
+            *w = y / *w;
+        }
+        Ok(tree.combine(&weights, 0, 0, points.len()))
+    }
+
+    // f(x + c) (標数は次数より大きいとする)
+    pub fn taylor_shift(&self, c: T) -> Self {
+        let n = self.0.len();
+        if n == 0 {
+            return self.clone();
+        }
+        let mut factorial = vec![T::one(); n];
+        for i in 1..n {
+            factorial[i] = factorial[i - 1] * T::from(i);
+        }
+        // g_i = 1 / i! Σ_j f_j j! c^(j - i) / (j - i)!
+        let a: Vec<T> = (0..n).rev().map(|j| self[j] * factorial[j]).collect();
+        let mut b = vec![T::one(); n];
+        for k in 1..n {
+            b[k] = b[k - 1] * c;
+        }
+        for k in 0..n {
+            b[k] /= factorial[k];
+        }
+        let conv = T::convolution(&a, &b);
+        Polynomial::from(
+            (0..n)
+                .map(|i| conv[n - 1 - i] / factorial[i])
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+// f(0), f(1), ..., f(n - 1) から次数 n 未満の f の f(x) を O(n) で求める
+// (標数は n より大きいとする)
+pub fn lagrange_consecutive<T: Analysis>(values: &[T], x: T) -> T {
+    let n = values.len();
+    if n == 0 {
+        return T::zero();
+    }
+    // left[i] = Π_{j < i} (x - j), right[i] = Π_{j > i} (x - j)
+    let mut left = vec![T::one(); n];
+    let mut right = vec![T::one(); n];
+    for i in 1..n {
+        left[i] = left[i - 1] * (x - T::from(i - 1));
+        right[n - 1 - i] = right[n - i] * (x - T::from(n - i));
+    }
+    let mut factorial = vec![T::one(); n];
+    for i in 1..n {
+        factorial[i] = factorial[i - 1] * T::from(i);
+    }
+    // Π_{j != i} (i - j) = (-1)^(n - 1 - i) i! (n - 1 - i)!
+    let mut sum = T::zero();
+    for i in 0..n {
+        let term = values[i] * left[i] * right[i] / (factorial[i] * factorial[n - 1 - i]);
+        if (n - 1 - i).is_multiple_of(2) {
+            sum += term;
+        } else {
+            sum -= term;
+        }
+    }
+    sum
+}
+
+// 部分積木 (節点 k が区間 [l, r) に対する Π (x - x_i) を持つ)
+struct SubproductTree<'a, T: Analysis> {
+    points: &'a [T],
+    nodes: Vec<Polynomial<T>>,
+}
+
+impl<'a, T: Analysis> SubproductTree<'a, T> {
+    fn new(points: &'a [T]) -> Self {
+        let mut tree = SubproductTree {
+            points,
+            nodes: vec![Polynomial::zero(); 4 * points.len()],
+        };
+        tree.build(0, 0, points.len());
+        tree
+    }
+
+    fn build(&mut self, k: usize, l: usize, r: usize) {
+        if r - l == 1 {
+            self.nodes[k] = Polynomial::from(vec![-self.points[l], T::one()]);
+            return;
+        }
+        let mid = (l + r) / 2;
+        self.build(2 * k + 1, l, mid);
+        self.build(2 * k + 2, mid, r);
+        self.nodes[k] = self.nodes[2 * k + 1].clone() * self.nodes[2 * k + 2].clone();
+    }
+
+    fn evaluate(&self, f: Polynomial<T>, k: usize, l: usize, r: usize, values: &mut [T]) {
+        let f = f.fast_div_rem(&self.nodes[k]).unwrap().1;
+        if r - l <= NAIVE_EVALUATION_THRESHOLD {
+            for (v, x) in values[l..r].iter_mut().zip(&self.points[l..r]) {
+                *v = f.evaluate(x);
+            }
+            return;
+        }
+        let mid = (l + r) / 2;
+        self.evaluate(f.clone(), 2 * k + 1, l, mid, values);
+        self.evaluate(f, 2 * k + 2, mid, r, values);
+    }
+
+    // Σ w_i Π_{j != i} (x - x_j)
+    fn combine(&self, weights: &[T], k: usize, l: usize, r: usize) -> Polynomial<T> {
+        if r - l == 1 {
+            return Polynomial::from(vec![weights[l]]);
+        }
+        let mid = (l + r) / 2;
+        let left = self.combine(weights, 2 * k + 1, l, mid);
+        let right = self.combine(weights, 2 * k + 2, mid, r);
+        left * self.nodes[2 * k + 2].clone() + right * self.nodes[2 * k + 1].clone()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+    use crate::algebraic::One;
+    use rand::Rng;
+
+    type Mint = ModInt998244353;
+
+    fn random_vec(rng: &mut rand::rngs::ThreadRng, len: usize) -> Vec<Mint> {
+        (0..len).map(|_| Mint::from(rng.gen::<u32>())).collect()
+    }
+
+    #[test]
+    fn fast_division() {
+        let mut rng = rand::thread_rng();
+        for (n, m) in [(300, 100), (500, 40), (100, 100), (1000, 999), (50, 80)] {
+            let a = Polynomial::from(random_vec(&mut rng, n));
+            let b = Polynomial::from(random_vec(&mut rng, m));
+            assert_eq!(a.fast_div_rem(&b), a.div_rem(&b));
+        }
+    }
+
+    #[test]
+    fn multipoint() {
+        let mut rng = rand::thread_rng();
+        for (n, m) in [(0, 5), (1, 1), (10, 3), (300, 500), (700, 100)] {
+            let f = Polynomial::from(random_vec(&mut rng, n));
+            let points = random_vec(&mut rng, m);
+            let expected: Vec<Mint> = points.iter().map(|x| f.evaluate(x)).collect();
+            assert_eq!(f.multipoint_evaluate(&points), expected);
+        }
+        // 1 + 2x + 3x^2
+        let f = Polynomial::from(vec![Mint::from(1), Mint::from(2), Mint::from(3)]);
+        assert_eq!(f.evaluate(&Mint::from(2)), Mint::from(17));
+        assert_eq!(f.multipoint_evaluate(&[]), vec![]);
+    }
+
+    #[test]
+    fn interpolation() {
+        let mut rng = rand::thread_rng();
+        for n in [0, 1, 2, 20, 300] {
+            let f = Polynomial::from(random_vec(&mut rng, n));
+            let pairs: Vec<(Mint, Mint)> = (0..n)
+                .map(|i| {
+                    let x = Mint::from(i * i + 7);
+                    (x, f.evaluate(&x))
+                })
+                .collect();
+            assert_eq!(Polynomial::interpolate(&pairs), Ok(f.clone()));
+            // 連続する点からの値
+            let values: Vec<Mint> = (0..n).map(|i| f.evaluate(&Mint::from(i))).collect();
+            for x in [0, 1, n, 12345] {
+                assert_eq!(
+                    lagrange_consecutive(&values, Mint::from(x)),
+                    f.evaluate(&Mint::from(x))
+                );
+            }
+        }
+        let pairs = [
+            (Mint::from(1), Mint::from(2)),
+            (Mint::from(1), Mint::from(3)),
+        ];
+        assert_eq!(
+            Polynomial::interpolate(&pairs),
+            Err(PolynomialError::DuplicatePoints)
+        );
+    }
+
+    #[test]
+    fn taylor_shift() {
+        let mut rng = rand::thread_rng();
+        for n in [0, 1, 5, 200] {
+            let f = Polynomial::from(random_vec(&mut rng, n));
+            let c = Mint::from(rng.gen::<u32>());
+            let g = f.taylor_shift(c);
+            for x in random_vec(&mut rng, 10) {
+                assert_eq!(g.evaluate(&x), f.evaluate(&(x + c)));
+            }
+        }
+        // (x + 1)^3
+        let f = Polynomial::from(vec![Mint::zero(), Mint::zero(), Mint::zero(), Mint::one()]);
+        let expected: Vec<Mint> = [1, 3, 3, 1].into_iter().map(Mint::from).collect();
+        assert_eq!(f.taylor_shift(Mint::one()).coeff(), expected);
+    }
+}
@@ -0,0 +1,351 @@
+use super::Polynomial;
+use crate::algebraic::ring::integer::prime::Primality;
+use crate::algebraic::ring::integer::ZZ;
+use crate::algebraic::ring::integer_mod::{IntegerMod, IntegerModRing};
+use crate::algebraic::ring::{Factor, UFD};
+use crate::algebraic::{One, ScalarMul, Zero};
+use num::ToPrimitive;
+
+type Poly = Polynomial<IntegerMod>;
+
+// a b mod m
+fn mul_mod(a: &Poly, b: &Poly, m: &Poly) -> Poly {
+    (a.clone() * b.clone()).div_rem(m).unwrap().1
+}
+
+// a^e mod m
+fn pow_mod(a: &Poly, e: &ZZ, m: &Poly) -> Poly {
+    let a = a.div_rem(m).unwrap().1;
+    let mut result = Poly::one().div_rem(m).unwrap().1;
+    for i in (0..e.bits()).rev() {
+        result = mul_mod(&result, &result, m);
+        if e.bit(i) {
+            result = mul_mod(&result, &a, m);
+        }
+    }
+    result
+}
+
+fn derivative(f: &Poly) -> Poly {
+    Polynomial::from(
+        (1..f.0.len())
+            .map(|i| f[i].scalar_mul(i))
+            .collect::<Vec<_>>(),
+    )
+}
+
+impl Polynomial<IntegerMod> {
+    // 係数体 Z/pZ (p が素数でなければ panic する)
+    fn prime_field(&self) -> IntegerModRing {
+        let p = self
+            .0
+            .iter()
+            .map(|c| c.modulus())
+            .find(|m| !m.is_zero())
+            .expect("coefficients must lie in Z/pZ");
+        assert!(p.is_prime(), "modulus must be prime");
+        IntegerModRing::new(p)
+    }
+
+    // 不定元 x (法 p)
+    fn indeterminate(ring: &IntegerModRing) -> Self {
+        Polynomial::from(vec![ring.zero(), ring.one()])
+    }
+
+    // 無平方分解 (モニックな f_i と重複度 e_i で self = lc Π f_i^e_i)
+    pub fn square_free_factorization(&self) -> Vec<Factor<Self>> {
+        if self.degree().unwrap_or(0) == 0 {
+            return Vec::new();
+        }
+        let p = self.prime_field().characteristic();
+        let mut factors = Vec::new();
+        let mut f = self.monic().unwrap();
+        let mut multiplier = 1;
+        loop {
+            let df = derivative(&f);
+            let mut c = f.gcd(&df);
+            if !df.is_zero() {
+                // w は重複度が p の倍数でない因子の積
+                let mut w = f.div_rem(&c).unwrap().0;
+                let mut i = 1;
+                while w.degree() > Some(0) {
+                    let y = w.gcd(&c);
+                    let factor = w.div_rem(&y).unwrap().0;
+                    if factor.degree() > Some(0) {
+                        factors.push(Factor {
+                            p: factor,
+                            e: i * multiplier,
+                        });
+                    }
+                    c = c.div_rem(&y).unwrap().0;
+                    w = y;
+                    i += 1;
+                }
+            }
+            if c.degree() == Some(0) {
+                break;
+            }
+            // 残りは p 乗なので p 乗根を取る (F_p では係数はそのまま)
+            let step = p.to_usize().unwrap();
+            f = Polynomial::from(c.0.iter().step_by(step).cloned().collect::<Vec<_>>());
+            multiplier *= step;
+        }
+        factors
+    }
+
+    // 無平方なモニック多項式を, 次数 d の既約因子の積 g_d ごとに (g_d, d) へ分ける
+    pub fn distinct_degree_factorization(&self) -> Vec<(Self, usize)> {
+        let ring = self.prime_field();
+        let p = ring.characteristic();
+        let x = Self::indeterminate(&ring);
+        let mut f = self.monic().unwrap();
+        let mut factors = Vec::new();
+        let mut h = x.clone();
+        let mut d = 1;
+        while f.degree().unwrap() >= 2 * d {
+            // h = x^(p^d) mod f
+            h = pow_mod(&h, &p, &f);
+            let g = f.gcd(&(h.clone() - x.clone()));
+            if g.degree() > Some(0) {
+                f = f.div_rem(&g).unwrap().0;
+                h = h.div_rem(&f).unwrap().1;
+                factors.push((g, d));
+            }
+            d += 1;
+        }
+        if let Some(n @ 1..) = f.degree() {
+            factors.push((f, n));
+        }
+        factors
+    }
+
+    // 次数 d の既約因子の積をモニックな既約因子に分ける (Cantor-Zassenhaus)
+    pub fn equal_degree_factorization(&self, d: usize) -> Vec<Self> {
+        let ring = self.prime_field();
+        let mut factors = Vec::new();
+        equal_degree_split(&self.monic().unwrap(), d, &ring, &mut factors);
+        factors
+    }
+
+    // 既約性判定 (Rabin)
+    pub fn is_irreducible(&self) -> bool {
+        let Some(n @ 1..) = self.degree() else {
+            return false;
+        };
+        let ring = self.prime_field();
+        let p = ring.characteristic();
+        let x = Self::indeterminate(&ring);
+        let f = self.monic().unwrap();
+        // frobenius[k] = x^(p^k) mod f
+        let mut frobenius = vec![x.div_rem(&f).unwrap().1];
+        for k in 0..n {
+            let next = pow_mod(&frobenius[k], &p, &f);
+            frobenius.push(next);
+        }
+        if frobenius[n] != frobenius[0] {
+            return false;
+        }
+        n.factors().into_iter().all(|Factor { p: q, .. }| {
+            let g = f.gcd(&(frobenius[n / q].clone() - x.clone()));
+            g.degree() == Some(0)
+        })
+    }
+
+    // 相異なる根 (昇順)
+    pub fn roots(&self) -> Vec<IntegerMod> {
+        assert!(!self.is_zero(), "every element is a root of zero");
+        if self.degree() == Some(0) {
+            return Vec::new();
+        }
+        let ring = self.prime_field();
+        let x = Self::indeterminate(&ring);
+        let f = self.monic().unwrap();
+        // 根は x^p - x との gcd の 1 次因子
+        let g = f.gcd(&(pow_mod(&x, &ring.characteristic(), &f) - x));
+        let mut roots: Vec<IntegerMod> = if g.degree() > Some(0) {
+            g.equal_degree_factorization(1)
+                .into_iter()
+                .map(|h| -h[0].clone())
+                .collect()
+        } else {
+            Vec::new()
+        };
+        roots.sort_by_key(|r| r.value());
+        roots
+    }
+}
+
+fn equal_degree_split(f: &Poly, d: usize, ring: &IntegerModRing, factors: &mut Vec<Poly>) {
+    let n = f.degree().unwrap();
+    if n == d {
+        factors.push(f.clone());
+        return;
+    }
+    let p = ring.characteristic();
+    loop {
+        let a = Polynomial::from((0..n).map(|_| ring.random_element()).collect::<Vec<_>>());
+        if a.degree() < Some(1) {
+            continue;
+        }
+        let b = if p == ZZ::from(2) {
+            // トレース a + a^2 + ... + a^(2^(d - 1)) は各既約因子を法として F_2 の元
+            let mut t = a.div_rem(f).unwrap().1;
+            let mut b = t.clone();
+            for _ in 1..d {
+                t = mul_mod(&t, &t, f);
+                b += t.clone();
+            }
+            b
+        } else {
+            // a^((p^d - 1) / 2) - 1
+            let e: ZZ = (p.pow(d as u32) - 1u32) / 2u32;
+            pow_mod(&a, &e, f) - Polynomial::one()
+        };
+        let g = f.gcd(&b);
+        if g.degree() > Some(0) && g.degree() < Some(n) {
+            let h = f.div_rem(&g).unwrap().0;
+            equal_degree_split(&g, d, ring, factors);
+            equal_degree_split(&h.monic().unwrap(), d, ring, factors);
+            return;
+        }
+    }
+}
+
+// モニックな既約因子と重複度 (定数倍は落とし, 次数と係数の順に並べる)
+impl UFD for Polynomial<IntegerMod> {
+    fn factors(self) -> Vec<Factor<Self>> {
+        let mut factors = Vec::new();
+        for Factor { p: g, e } in self.square_free_factorization() {
+            for (h, d) in g.distinct_degree_factorization() {
+                for q in h.equal_degree_factorization(d) {
+                    factors.push(Factor { p: q, e });
+                }
+            }
+        }
+        factors.sort_by_key(|f| {
+            let coeff: Vec<ZZ> = f.p.0.iter().map(|c| c.value()).collect();
+            (f.p.degree(), coeff.into_iter().rev().collect::<Vec<_>>())
+        });
+        factors
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    fn poly(ring: &IntegerModRing, coeff: &[i64]) -> Poly {
+        Polynomial::from(
+            coeff
+                .iter()
+                .map(|&c| ring.elem(c.into()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn random_poly(ring: &IntegerModRing, len: usize) -> Poly {
+        Polynomial::from((0..len).map(|_| ring.random_element()).collect::<Vec<_>>())
+    }
+
+    fn product(ring: &IntegerModRing, factors: &[Factor<Poly>]) -> Poly {
+        factors
+            .iter()
+            .fold(poly(ring, &[1]), |acc, Factor { p, e }| {
+                acc * (p.clone() ^ *e)
+            })
+    }
+
+    #[test]
+    fn factorize_random() {
+        let mut rng = rand::thread_rng();
+        for p in [2i64, 3, 5, 7, 13, 1_000_000_007] {
+            let ring = IntegerModRing::new(p.into());
+            for _ in 0..10 {
+                // 重複する因子を含むようにする
+                let g = random_poly(&ring, rng.gen_range(1..4));
+                let f = random_poly(&ring, rng.gen_range(1..8)) * (g.clone() ^ 2) * g;
+                if f.is_zero() {
+                    continue;
+                }
+                let factors = f.clone().factors();
+                let lc = Polynomial::from(vec![f.leading_coefficient()]);
+                assert_eq!(product(&ring, &factors) * lc, f);
+                for Factor { p: q, .. } in &factors {
+                    assert!(q.is_irreducible(), "{}", q);
+                    assert!(q.leading_coefficient().is_one());
+                }
+                for w in factors.windows(2) {
+                    assert_ne!(w[0].p, w[1].p);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn small_cases() {
+        let f2 = IntegerModRing::new(2.into());
+        // x^4 + x = x (x + 1) (x^2 + x + 1) over F_2
+        let factors = poly(&f2, &[0, 1, 0, 0, 1]).factors();
+        let expected: Vec<Poly> = vec![
+            poly(&f2, &[0, 1]),
+            poly(&f2, &[1, 1]),
+            poly(&f2, &[1, 1, 1]),
+        ];
+        assert_eq!(
+            factors.into_iter().map(|f| f.p).collect::<Vec<_>>(),
+            expected
+        );
+        // x^2 + 1 = (x + 1)^2 over F_2
+        let factors = poly(&f2, &[1, 0, 1]).factors();
+        assert_eq!(factors.len(), 1);
+        assert_eq!(
+            (factors[0].p.clone(), factors[0].e),
+            (poly(&f2, &[1, 1]), 2)
+        );
+        // x^p - x は F_p の全元の積
+        let f5 = IntegerModRing::new(5.into());
+        let f = poly(&f5, &[0, -1, 0, 0, 0, 1]);
+        assert_eq!(f.clone().factors().len(), 5);
+        assert_eq!(
+            f.roots(),
+            (0..5).map(|i| f5.elem(i.into())).collect::<Vec<_>>()
+        );
+        assert!(poly(&f5, &[7]).factors().is_empty());
+        assert!(Poly::zero().factors().is_empty());
+    }
+
+    #[test]
+    fn irreducibility_and_roots() {
+        for p in [2u32, 3, 7] {
+            let ring = IntegerModRing::new(p.into());
+            // 次数 3 以下なら既約性は根を持たないことと同値
+            for n in 0..(p as usize).pow(4) {
+                let coeff: Vec<i64> = (0..4)
+                    .map(|i| (n / (p as usize).pow(i)) as i64 % p as i64)
+                    .collect();
+                let f = poly(&ring, &coeff);
+                if f.is_zero() {
+                    continue;
+                }
+                let roots: Vec<IntegerMod> =
+                    ring.iter().filter(|x| f.evaluate(x).is_zero()).collect();
+                assert_eq!(f.roots(), roots);
+                let deg = f.degree().unwrap();
+                if (2..=3).contains(&deg) {
+                    assert_eq!(f.is_irreducible(), roots.is_empty(), "{}", f);
+                }
+            }
+        }
+        // x^4 + x + 1 は F_2 上既約, x^4 + x^2 + 1 = (x^2 + x + 1)^2 は可約
+        let f2 = IntegerModRing::new(2.into());
+        assert!(poly(&f2, &[1, 1, 0, 0, 1]).is_irreducible());
+        assert!(!poly(&f2, &[1, 0, 1, 0, 1]).is_irreducible());
+        assert!(!poly(&f2, &[1]).is_irreducible());
+        // 大きい素数での根
+        let ring = IntegerModRing::new(1_000_000_007.into());
+        let f = poly(&ring, &[-6, 11, -6, 1]);
+        let expected: Vec<IntegerMod> = (1..=3).map(|i| ring.elem(i.into())).collect();
+        assert_eq!(f.roots(), expected);
+    }
+}
@@ -0,0 +1,236 @@
+use super::{CRing, Polynomial};
+use crate::algebraic::{Field, One, Zero};
+
+// これより小さいと Euclid の互除法の方が速い
+pub(super) const HALF_GCD_THRESHOLD: usize = 64;
+
+// 2 × 2 の多項式行列 [[a, b], [c, d]]
+type Matrix<T> = [[Polynomial<T>; 2]; 2];
+
+fn identity<T: CRing>() -> Matrix<T> {
+    [
+        [Polynomial::one(), Polynomial::zero()],
+        [Polynomial::zero(), Polynomial::one()],
+    ]
+}
+
+fn mul<T: CRing>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+    let entry =
+        |i: usize, j: usize| a[i][0].clone() * b[0][j].clone() + a[i][1].clone() * b[1][j].clone();
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
+
+fn apply<T: CRing>(m: &Matrix<T>, a: &Polynomial<T>, b: &Polynomial<T>) -> [Polynomial<T>; 2] {
+    [
+        m[0][0].clone() * a.clone() + m[0][1].clone() * b.clone(),
+        m[1][0].clone() * a.clone() + m[1][1].clone() * b.clone(),
+    ]
+}
+
+// 互除法の 1 段 [[0, 1], [1, -q]] を左から掛ける
+fn step<T: CRing>(m: Matrix<T>, q: &Polynomial<T>) -> Matrix<T> {
+    let [row0, row1] = m;
+    let next = [
+        row0[0].clone() - q.clone() * row1[0].clone(),
+        row0[1].clone() - q.clone() * row1[1].clone(),
+    ];
+    [row1, next]
+}
+
+impl<T: CRing + Field> Polynomial<T> {
+    // x^k で割った商
+    fn shift_down(&self, k: usize) -> Self {
+        Polynomial(self.0.iter().skip(k).cloned().collect())
+    }
+
+    // deg a > deg b のとき, (a, b) を deg a' >= ceil(deg a / 2) > deg b' となる
+    // 剰余列の隣り合う 2 項 (a', b') に移す行列 (途中の商は quotients に積む)
+    fn half_gcd(a: &Self, b: &Self, quotients: &mut Vec<Self>) -> Matrix<T> {
+        let m = a.0.len() / 2;
+        if b.degree() < Some(m) {
+            return identity();
+        }
+        if a.0.len() <= HALF_GCD_THRESHOLD {
+            let (mut a, mut b) = (a.clone(), b.clone());
+            let mut matrix = identity();
+            while b.degree() >= Some(m) {
+                let (q, r) = a.div_rem(&b).unwrap();
+                matrix = step(matrix, &q);
+                quotients.push(q);
+                (a, b) = (b, r);
+            }
+            return matrix;
+        }
+        // 上位の係数だけで剰余列の前半を求める
+        let r = Self::half_gcd(&a.shift_down(m), &b.shift_down(m), quotients);
+        let [a, b] = apply(&r, a, b);
+        if b.degree() < Some(m) {
+            return r;
+        }
+        let (q, c) = a.div_rem(&b).unwrap();
+        let r = step(r, &q);
+        quotients.push(q);
+        if c.degree() < Some(m) {
+            return r;
+        }
+        let k = 2 * m - (b.0.len() - 1);
+        let s = Self::half_gcd(&b.shift_down(k), &c.shift_down(k), quotients);
+        mul(&s, &r)
+    }
+
+    // Euclid の互除法を最後まで進めた (r, M, 商の列) (M (self, rhs) = (r, 0))
+    pub(super) fn euclid(&self, rhs: &Self) -> (Self, Matrix<T>, Vec<Self>) {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        let mut matrix = identity();
+        let mut quotients = Vec::new();
+        while !b.is_zero() {
+            let (q, r) = a.div_rem(&b).unwrap();
+            matrix = step(matrix, &q);
+            quotients.push(q);
+            (a, b) = (b, r);
+            if b.is_zero() {
+                break;
+            }
+            let h = Self::half_gcd(&a, &b, &mut quotients);
+            [a, b] = apply(&h, &a, &b);
+            matrix = mul(&h, &matrix);
+        }
+        (a, matrix, quotients)
+    }
+
+    // 終結式 (half-GCD で O(M(n) log n))
+    pub fn fast_resultant(&self, rhs: &Self) -> T {
+        if self.is_zero() || rhs.is_zero() {
+            return T::zero();
+        }
+        let (n, m) = (self.0.len() - 1, rhs.0.len() - 1);
+        if n < m {
+            let res = rhs.fast_resultant(self);
+            return if n * m % 2 == 0 { res } else { -res };
+        }
+        if m == 0 {
+            return rhs[0].scalar_pow(n);
+        }
+        // 剰余 r_i の次数と最高次係数は商から決まる
+        // res(r_{i-1}, r_i) = (-1)^(d_{i-1} d_i) c_i^(d_{i-1} - d_{i+1}) res(r_i, r_{i+1})
+        let quotients = self.euclid(rhs).2;
+        let (mut d_prev, mut d) = (n, m);
+        let mut c = rhs.leading_coefficient();
+        let mut res = T::one();
+        for q in &quotients[1..] {
+            let d_next = d - (q.0.len() - 1);
+            if d_prev * d % 2 == 1 {
+                res = -res;
+            }
+            res *= c.scalar_pow(d_prev - d_next);
+            (d_prev, d) = (d, d_next);
+            c /= q.leading_coefficient();
+        }
+        if d > 0 {
+            return T::zero();
+        }
+        res * c.scalar_pow(d_prev)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+    use crate::algebraic::ring::integer_mod::IntegerModRing;
+    use rand::Rng;
+
+    type Mint = ModInt998244353;
+
+    fn random_poly(rng: &mut rand::rngs::ThreadRng, len: usize) -> Polynomial<Mint> {
+        Polynomial::from(
+            (0..len)
+                .map(|_| Mint::from(rng.gen::<u32>()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn quotients() {
+        let mut rng = rand::thread_rng();
+        for (n, m) in [
+            (2, 1),
+            (10, 3),
+            (100, 99),
+            (300, 150),
+            (500, 20),
+            (1500, 1499),
+        ] {
+            let a = random_poly(&mut rng, n);
+            let b = random_poly(&mut rng, m);
+            // 素朴な互除法と一致する
+            let (mut x, mut y) = (a.clone(), b.clone());
+            let mut expected = Vec::new();
+            while !y.is_zero() {
+                let (q, r) = x.div_rem(&y).unwrap();
+                expected.push(q);
+                (x, y) = (y, r);
+            }
+            assert_eq!(a.euclid(&b).2, expected);
+        }
+    }
+
+    #[test]
+    fn resultant() {
+        let mut rng = rand::thread_rng();
+        for (n, m) in [(1, 1), (1, 5), (6, 4), (20, 20), (40, 13), (31, 30)] {
+            let a = random_poly(&mut rng, n);
+            let b = random_poly(&mut rng, m);
+            assert_eq!(a.fast_resultant(&b), a.resultant(&b));
+            // 共通因子があれば 0
+            let g = random_poly(&mut rng, 3);
+            assert!((a.clone() * g.clone()).fast_resultant(&(b * g)).is_zero());
+        }
+        let a = random_poly(&mut rng, 1000);
+        let b = random_poly(&mut rng, 700);
+        let c = random_poly(&mut rng, 2);
+        // res(a, bc) = res(a, b) res(a, c)
+        assert_eq!(
+            a.fast_resultant(&(b.clone() * c.clone())),
+            a.fast_resultant(&b) * a.fast_resultant(&c)
+        );
+    }
+
+    #[test]
+    fn gcd() {
+        let mut rng = rand::thread_rng();
+        for (n, m, k) in [(100, 80, 2), (600, 500, 100), (1000, 1000, 300)] {
+            let g = random_poly(&mut rng, k).monic().unwrap();
+            let a = random_poly(&mut rng, n) * g.clone();
+            let b = random_poly(&mut rng, m) * g.clone();
+            let (d, s, t) = a.xgcd(&b);
+            assert_eq!(d, g);
+            assert_eq!(a.gcd(&b), g);
+            assert_eq!(a.clone() * s.clone() + b.clone() * t.clone(), d);
+            // 素朴な互除法と同じ最小の係数
+            assert!(s.degree() < b.degree());
+            assert!(t.degree() < a.degree());
+            // 法 a での逆元
+            let f = random_poly(&mut rng, 700);
+            let inv = f.mod_inverse(&a).unwrap();
+            assert!(inv.degree() < a.degree());
+            assert!((f * inv - Polynomial::one())
+                .div_rem(&a)
+                .unwrap()
+                .1
+                .is_zero());
+            assert_eq!(b.mod_inverse(&a), None);
+        }
+        // 素体 F_p 上の多項式
+        let ring = IntegerModRing::new(1_000_000_007.into());
+        let poly = |len: usize| {
+            Polynomial::from((0..len).map(|_| ring.random_element()).collect::<Vec<_>>())
+        };
+        let g = poly(40).monic().unwrap();
+        let a = poly(200) * g.clone();
+        let b = poly(150) * g.clone();
+        let (d, s, t) = a.xgcd(&b);
+        assert_eq!(d, g);
+        assert_eq!(a * s + b * t, d);
+    }
+}
@@ -0,0 +1,465 @@
+use super::Polynomial;
+use crate::algebraic::ring::integer::prime::Primality;
+use crate::algebraic::ring::integer::{QQ, ZZ};
+use crate::algebraic::ring::integer_mod::{IntegerMod, IntegerModRing};
+use crate::algebraic::ring::{EuclidDomain, Factor, UFD};
+use crate::algebraic::{One, ScalarMul, Zero};
+use num::{Integer, Signed};
+
+type Poly = Polynomial<ZZ>;
+
+// 因数分解に使う素数の候補数 (因子の最も少ないものを選ぶ)
+const PRIME_CANDIDATES: usize = 5;
+
+impl Polynomial<ZZ> {
+    // 内容 (係数の gcd に最高次の係数の符号を付けたもの, 零多項式は 0)
+    pub fn content(&self) -> ZZ {
+        let g = ZZ::gcd_all(self.0.iter().cloned());
+        if self.leading_coefficient().is_negative() {
+            -g
+        } else {
+            g
+        }
+    }
+
+    // 原始部分 (最高次の係数は正)
+    pub fn primitive_part(&self) -> Self {
+        let c = self.content();
+        if c.is_zero() {
+            return self.clone();
+        }
+        Polynomial::from(self.0.iter().map(|x| x / &c).collect::<Vec<_>>())
+    }
+
+    // 割り切れるときの商
+    pub fn div_exact(&self, rhs: &Self) -> Option<Self> {
+        assert!(!rhs.is_zero(), "division by the zero polynomial");
+        let (n, m) = (self.0.len(), rhs.0.len());
+        if n < m {
+            return self.is_zero().then(Polynomial::zero);
+        }
+        let lc = rhs.leading_coefficient();
+        let mut q = vec![ZZ::zero(); n - m + 1];
+        let mut r = self.0.clone();
+        for i in (0..=n - m).rev() {
+            let (c, rem) = r[i + m - 1].div_rem(&lc);
+            if !rem.is_zero() {
+                return None;
+            }
+            for j in 0..m {
+                r[i + j] -= &c * &rhs[j];
+            }
+            q[i] = c;
+        }
+        r.iter().all(|x| x.is_zero()).then(|| Polynomial::from(q))
+    }
+
+    // 原始的な最大公約数 (最高次の係数は正)
+    pub fn primitive_gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.primitive_part(), rhs.primitive_part());
+        while !b.is_zero() {
+            let r = a.pseudo_rem(&b).unwrap().primitive_part();
+            (a, b) = (b, r);
+        }
+        let c = <ZZ as EuclidDomain>::gcd(self.content(), rhs.content());
+        a * Polynomial::from(vec![c])
+    }
+
+    fn derivative(&self) -> Self {
+        Polynomial::from(
+            (1..self.0.len())
+                .map(|i| self[i].scalar_mul(i))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    // 原始部分の無平方分解 (Yun, 各因子は原始的で最高次の係数は正)
+    pub fn square_free_factorization(&self) -> Vec<Factor<Self>> {
+        let f = self.primitive_part();
+        if f.degree().unwrap_or(0) == 0 {
+            return Vec::new();
+        }
+        let df = f.derivative();
+        let c = f.primitive_gcd(&df);
+        let mut w = f.div_exact(&c).unwrap();
+        let mut y = df.div_exact(&c).unwrap();
+        let mut z = y - w.derivative();
+        let mut factors = Vec::new();
+        let mut i = 1;
+        while w.degree() > Some(0) {
+            let g = w.primitive_gcd(&z).primitive_part();
+            w = w.div_exact(&g).unwrap();
+            y = z.div_exact(&g).unwrap();
+            z = y - w.derivative();
+            if g.degree() > Some(0) {
+                factors.push(Factor { p: g, e: i });
+            }
+            i += 1;
+        }
+        factors
+    }
+}
+
+fn to_mod(f: &Poly, ring: &IntegerModRing) -> Polynomial<IntegerMod> {
+    Polynomial::from(f.0.iter().map(|c| ring.elem(c.clone())).collect::<Vec<_>>())
+}
+
+fn from_mod(f: &Polynomial<IntegerMod>) -> Poly {
+    Polynomial::from(f.0.iter().map(|c| c.value()).collect::<Vec<_>>())
+}
+
+// 係数を [0, m) に落とす
+fn reduce(f: &Poly, m: &ZZ) -> Poly {
+    Polynomial::from(f.0.iter().map(|c| c.mod_floor(m)).collect::<Vec<_>>())
+}
+
+// 係数を (-m/2, m/2] に落とす
+fn symmetric(f: &Poly, m: &ZZ) -> Poly {
+    let half: ZZ = m / 2u32;
+    Polynomial::from(
+        f.0.iter()
+            .map(|c| {
+                let c = c.mod_floor(m);
+                if c > half {
+                    c - m
+                } else {
+                    c
+                }
+            })
+            .collect::<Vec<_>>(),
+    )
+}
+
+// f ≡ g h, s g + t h ≡ 1 (mod m) を mod m^2 に持ち上げる (h はモニック)
+fn hensel_step(f: &Poly, g: &Poly, h: &Poly, s: &Poly, t: &Poly, m: &ZZ) -> [Poly; 4] {
+    let m2 = m * m;
+    let e = reduce(&(f.clone() - g.clone() * h.clone()), &m2);
+    let (q, r) = (s.clone() * e.clone()).pseudo_div_rem(h).unwrap();
+    let g2 = reduce(&(g.clone() + t.clone() * e + q * g.clone()), &m2);
+    let h2 = reduce(&(h.clone() + r), &m2);
+    let b = reduce(
+        &(s.clone() * g2.clone() + t.clone() * h2.clone() - Polynomial::one()),
+        &m2,
+    );
+    let (c, d) = (s.clone() * b.clone()).pseudo_div_rem(&h2).unwrap();
+    let s2 = reduce(&(s.clone() - d), &m2);
+    let t2 = reduce(&(t.clone() - t.clone() * b - c * g2.clone()), &m2);
+    [g2, h2, s2, t2]
+}
+
+// f ≡ lc(f) Π factors (mod p) を mod modulus (p の 2 冪乗) のモニックな因子に持ち上げる
+fn multifactor_lift(
+    f: &Poly,
+    factors: &[Polynomial<IntegerMod>],
+    ring: &IntegerModRing,
+    modulus: &ZZ,
+) -> Vec<Poly> {
+    let lc = f.leading_coefficient();
+    if factors.len() == 1 {
+        let inv = ZZ::mod_inverse(lc, modulus.clone()).unwrap();
+        return vec![reduce(&(f.clone() * Polynomial::from(vec![inv])), modulus)];
+    }
+    let (left, right) = factors.split_at(factors.len() / 2);
+    let product = |v: &[Polynomial<IntegerMod>]| {
+        v.iter()
+            .fold(Polynomial::from(vec![ring.one()]), |acc, x| acc * x.clone())
+    };
+    let g0 = product(left) * Polynomial::from(vec![ring.elem(lc)]);
+    let h0 = product(right);
+    let (_, s0, t0) = g0.xgcd(&h0);
+    let (mut g, mut h, mut s, mut t) = (from_mod(&g0), from_mod(&h0), from_mod(&s0), from_mod(&t0));
+    let mut m = ring.characteristic();
+    while &m < modulus {
+        [g, h, s, t] = hensel_step(f, &g, &h, &s, &t, &m);
+        m = &m * &m;
+    }
+    let (g, h) = (reduce(&g, modulus), reduce(&h, modulus));
+    let mut lifted = multifactor_lift(&g, left, ring, modulus);
+    lifted.extend(multifactor_lift(&h, right, ring, modulus));
+    lifted
+}
+
+// 原始的で無平方な f (最高次の係数は正) の既約因子
+fn factor_square_free(f: &Poly) -> Vec<Poly> {
+    let n = f.degree().unwrap();
+    if n <= 1 {
+        return vec![f.clone()];
+    }
+    let lc = f.leading_coefficient();
+    // lc を割らず f mod p が無平方な素数で, 因子の最も少ないもの
+    let (ring, factors) = (3u64..)
+        .filter(|p| p.is_prime() && !(&lc % p).is_zero())
+        .map(|p| IntegerModRing::new(p.into()))
+        .filter(|ring| {
+            let g = to_mod(f, ring);
+            g.gcd(&to_mod(&f.derivative(), ring)).degree() == Some(0)
+        })
+        .take(PRIME_CANDIDATES)
+        .map(|ring| {
+            let factors: Vec<_> = to_mod(f, &ring)
+                .factors()
+                .into_iter()
+                .map(|x| x.p)
+                .collect();
+            (ring, factors)
+        })
+        .min_by_key(|(_, factors)| factors.len())
+        .unwrap();
+    if factors.len() == 1 {
+        return vec![f.clone()];
+    }
+    // Mignotte の評価で因子 (の lc 倍) の係数は |lc| 2^n ||f||_2 以下
+    let norm = f.0.iter().map(|c| c * c).sum::<ZZ>().sqrt() + 1u32;
+    let bound: ZZ = (lc.abs() * norm) << (n + 1);
+    let p = ring.characteristic();
+    let mut modulus = p.clone();
+    while modulus <= bound {
+        modulus = &modulus * &modulus;
+    }
+    let mut lifted = multifactor_lift(f, &factors, &ring, &modulus);
+    // Zassenhaus: 小さい部分集合から順に試し割りする
+    let mut f = f.clone();
+    let mut result = Vec::new();
+    let mut size = 1;
+    while 2 * size <= lifted.len() {
+        let mut found = false;
+        for subset in combinations(lifted.len(), size) {
+            let lc = Polynomial::from(vec![f.leading_coefficient()]);
+            let candidate = subset
+                .iter()
+                .fold(lc, |acc, &i| reduce(&(acc * lifted[i].clone()), &modulus));
+            let g = symmetric(&candidate, &modulus).primitive_part();
+            if let Some(q) = f.div_exact(&g) {
+                result.push(g);
+                f = q;
+                lifted = (0..lifted.len())
+                    .filter(|i| !subset.contains(i))
+                    .map(|i| lifted[i].clone())
+                    .collect();
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            size += 1;
+        }
+    }
+    result.push(f.primitive_part());
+    result
+}
+
+// {0, ..., n - 1} の k 元部分集合を辞書順に
+fn combinations(n: usize, k: usize) -> impl Iterator<Item = Vec<usize>> {
+    let mut current: Option<Vec<usize>> = (k <= n).then(|| (0..k).collect());
+    std::iter::from_fn(move || {
+        let result = current.clone()?;
+        let c = current.as_mut().unwrap();
+        match (0..k).rev().find(|&i| c[i] != i + n - k) {
+            Some(i) => {
+                c[i] += 1;
+                for j in i + 1..k {
+                    c[j] = c[j - 1] + 1;
+                }
+            }
+            None => current = None,
+        }
+        Some(result)
+    })
+}
+
+fn sort_factors(factors: &mut [Factor<Poly>]) {
+    factors.sort_by_key(|f| {
+        (
+            f.p.degree(),
+            f.p.0.iter().rev().cloned().collect::<Vec<_>>(),
+        )
+    });
+}
+
+// 内容の素因数 (定数多項式) と原始的な既約因子 (最高次の係数は正), 符号は落とす
+impl UFD for Polynomial<ZZ> {
+    fn factors(self) -> Vec<Factor<Self>> {
+        if self.is_zero() {
+            return Vec::new();
+        }
+        let mut factors: Vec<Factor<Self>> = self
+            .content()
+            .abs()
+            .factors()
+            .into_iter()
+            .map(|Factor { p, e }| Factor {
+                p: Polynomial::from(vec![p]),
+                e,
+            })
+            .collect();
+        for Factor { p: g, e } in self.square_free_factorization() {
+            for q in factor_square_free(&g) {
+                factors.push(Factor { p: q, e });
+            }
+        }
+        sort_factors(&mut factors);
+        factors
+    }
+}
+
+// モニックな既約因子 (定数倍は落とす)
+impl UFD for Polynomial<QQ> {
+    fn factors(self) -> Vec<Factor<Self>> {
+        // 分母を払って整数係数にする
+        let denominator = ZZ::lcm_all(self.0.iter().map(|c| c.denom().clone()));
+        let f: Poly = Polynomial::from(
+            self.0
+                .iter()
+                .map(|c| (c * QQ::from_integer(denominator.clone())).to_integer())
+                .collect::<Vec<_>>(),
+        );
+        f.factors()
+            .into_iter()
+            .filter(|Factor { p, .. }| p.degree() > Some(0))
+            .map(|Factor { p, e }| {
+                let g = Polynomial::from(
+                    p.0.iter()
+                        .map(|c| QQ::from_integer(c.clone()))
+                        .collect::<Vec<_>>(),
+                );
+                Factor {
+                    p: g.monic().unwrap(),
+                    e,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::Rng;
+
+    fn zz(coeff: &[i64]) -> Poly {
+        Polynomial::from(coeff.iter().map(|&c| ZZ::from(c)).collect::<Vec<_>>())
+    }
+
+    fn product<T: super::super::CRing>(factors: &[Factor<Polynomial<T>>]) -> Polynomial<T> {
+        factors
+            .iter()
+            .fold(Polynomial::one(), |acc, Factor { p, e }| {
+                acc * (p.clone() ^ *e)
+            })
+    }
+
+    fn check(f: &Poly) -> Vec<Factor<Poly>> {
+        let factors = f.clone().factors();
+        let g = product(&factors);
+        assert!(g == *f || -g == *f, "{}", f);
+        for Factor { p, .. } in &factors {
+            assert!(p.leading_coefficient().is_positive());
+            if p.degree() > Some(0) {
+                assert!(p.content().is_one());
+            }
+        }
+        factors
+    }
+
+    #[test]
+    fn content_and_gcd() {
+        let f = zz(&[-6, 0, -12]);
+        assert_eq!(f.content(), ZZ::from(-6));
+        assert_eq!(f.primitive_part(), zz(&[1, 0, 2]));
+        assert_eq!(Poly::zero().content(), ZZ::zero());
+        // gcd((2x + 2)(x - 3), (4x + 4)(x^2 + 1)) = 2 (x + 1)
+        let a = zz(&[2, 2]) * zz(&[-3, 1]);
+        let b = zz(&[4, 4]) * zz(&[1, 0, 1]);
+        assert_eq!(a.primitive_gcd(&b), zz(&[2, 2]));
+        assert_eq!(a.div_exact(&zz(&[-3, 1])), Some(zz(&[2, 2])));
+        assert_eq!(a.div_exact(&zz(&[3, 1])), None);
+        assert_eq!(zz(&[1, 2]).div_exact(&zz(&[0, 2])), None);
+        let sf = (zz(&[1, 1]) ^ 3) * (zz(&[-2, 0, 1]) ^ 2) * zz(&[0, 3]);
+        let factors = sf.square_free_factorization();
+        let parts: Vec<(Poly, usize)> = factors.into_iter().map(|f| (f.p, f.e)).collect();
+        assert_eq!(
+            parts,
+            vec![(zz(&[0, 1]), 1), (zz(&[-2, 0, 1]), 2), (zz(&[1, 1]), 3)]
+        );
+    }
+
+    #[test]
+    fn known_factorizations() {
+        // x^4 + 1, x^4 - 10x^2 + 1 と Swinnerton-Dyer 多項式は既約だが, どの素数でも可約になる
+        let swinnerton_dyer = zz(&[576, 0, -960, 0, 352, 0, -40, 0, 1]);
+        for f in [
+            zz(&[1, 0, 0, 0, 1]),
+            zz(&[1, 0, -10, 0, 1]),
+            swinnerton_dyer,
+        ] {
+            let factors = check(&f);
+            assert_eq!(factors.len(), 1);
+            assert_eq!(factors[0].p, f);
+        }
+        // x^6 - 1 = (x - 1)(x + 1)(x^2 - x + 1)(x^2 + x + 1)
+        let factors: Vec<Poly> = check(&zz(&[-1, 0, 0, 0, 0, 0, 1]))
+            .into_iter()
+            .map(|f| f.p)
+            .collect();
+        assert_eq!(
+            factors,
+            vec![zz(&[-1, 1]), zz(&[1, 1]), zz(&[1, -1, 1]), zz(&[1, 1, 1])]
+        );
+        // -12x^2 + 12 = -(2^2)(3)(x - 1)(x + 1)
+        let factors = check(&zz(&[12, 0, -12]));
+        let parts: Vec<(Poly, usize)> = factors.into_iter().map(|f| (f.p, f.e)).collect();
+        assert_eq!(
+            parts,
+            vec![
+                (zz(&[2]), 2),
+                (zz(&[3]), 1),
+                (zz(&[-1, 1]), 1),
+                (zz(&[1, 1]), 1)
+            ]
+        );
+        // 最高次の係数が 1 でない場合
+        let f = zz(&[3, 5]) * zz(&[-7, 0, 6]) * zz(&[3, 1, 0, 4]);
+        assert_eq!(check(&f).len(), 3);
+        assert!(Poly::zero().factors().is_empty());
+        assert!(zz(&[-1]).factors().is_empty());
+    }
+
+    #[test]
+    fn random_products() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let k = rng.gen_range(1..4);
+            let mut f = zz(&[rng.gen_range(1..10)]);
+            for _ in 0..k {
+                let len = rng.gen_range(2..5);
+                let mut coeff: Vec<i64> = (0..len).map(|_| rng.gen_range(-9..10)).collect();
+                coeff[len - 1] = rng.gen_range(1..4);
+                f *= zz(&coeff) ^ rng.gen_range(1..3);
+            }
+            let factors = check(&f);
+            let count: usize = factors
+                .iter()
+                .filter(|x| x.p.degree() > Some(0))
+                .map(|x| x.e)
+                .sum();
+            assert!(count >= k, "{}", f);
+        }
+    }
+
+    #[test]
+    fn rationals() {
+        let q = |n: i64, d: i64| QQ::new(n.into(), d.into());
+        // (x / 2 + 1 / 3)(x^2 - 2)^2
+        let f = Polynomial::from(vec![q(1, 3), q(1, 2)])
+            * (Polynomial::from(vec![q(-2, 1), q(0, 1), q(1, 1)]) ^ 2);
+        let factors = f.factors();
+        let parts: Vec<(Polynomial<QQ>, usize)> = factors.into_iter().map(|f| (f.p, f.e)).collect();
+        assert_eq!(
+            parts,
+            vec![
+                (Polynomial::from(vec![q(2, 3), q(1, 1)]), 1),
+                (Polynomial::from(vec![q(-2, 1), q(0, 1), q(1, 1)]), 2)
+            ]
+        );
+    }
+}
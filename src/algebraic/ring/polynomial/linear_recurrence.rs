@@ -0,0 +1,212 @@
+use super::{CRing, Polynomial, PolynomialError};
+use crate::algebraic::Field;
+
+// a_n = c_1 a_(n-1) + ... + c_d a_(n-d) を満たす最短の (c_1, ..., c_d) (Berlekamp–Massey)
+pub fn berlekamp_massey<T: CRing + Field>(sequence: &[T]) -> Vec<T> {
+    let (mut c, mut b): (Vec<T>, Vec<T>) = (Vec::new(), Vec::new());
+    let (mut l, mut m) = (0, 1);
+    let mut last = T::one();
+    for i in 0..sequence.len() {
+        // 今の漸化式とのずれ
+        let mut d = sequence[i].clone();
+        for (j, cj) in c.iter().enumerate() {
+            d -= cj.clone() * sequence[i - 1 - j].clone();
+        }
+        if d.is_zero() {
+            m += 1;
+            continue;
+        }
+        // C(x) ← C(x) - d / last x^m B(x) (C(x) = 1 - Σ c_j x^j)
+        let coef = d.clone() / last.clone();
+        let prev = c.clone();
+        if c.len() < b.len() + m {
+            c.resize(b.len() + m, T::zero());
+        }
+        c[m - 1] += coef.clone();
+        for (j, bj) in b.iter().enumerate() {
+            c[m + j] -= coef.clone() * bj.clone();
+        }
+        if 2 * l <= i {
+            l = i + 1 - l;
+            (b, last, m) = (prev, d, 1);
+        } else {
+            m += 1;
+        }
+    }
+    c.resize(l, T::zero());
+    c
+}
+
+// P(x) / Q(x) の x^n の係数 (Bostan–Mori, Q(0) = 0 なら Err)
+pub fn nth_term<T: CRing + Field>(
+    p: &Polynomial<T>,
+    q: &Polynomial<T>,
+    mut n: u64,
+) -> Result<T, PolynomialError> {
+    if q.evaluate(&T::zero()).is_zero() {
+        return Err(PolynomialError::DivisionByZero);
+    }
+    let (mut p, mut q) = (p.clone(), q.clone());
+    while n > 0 {
+        // P(x) Q(-x) / Q(x) Q(-x) の分母は x^2 の多項式になる
+        let minus = Polynomial::from(
+            q.0.iter()
+                .enumerate()
+                .map(|(i, c)| if i % 2 == 0 { c.clone() } else { -c.clone() })
+                .collect::<Vec<_>>(),
+        );
+        let u = p * minus.clone();
+        let v = q * minus;
+        let parity = (n % 2) as usize;
+        p = Polynomial::from(u.0.into_iter().skip(parity).step_by(2).collect::<Vec<_>>());
+        q = Polynomial::from(v.0.into_iter().step_by(2).collect::<Vec<_>>());
+        n /= 2;
+    }
+    Ok(p.evaluate(&T::zero()) / q.evaluate(&T::zero()))
+}
+
+// 線形漸化式 a_n = c_1 a_(n-1) + ... + c_d a_(n-d) と初項 a_0, ..., a_(d-1)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearRecurrence<T: CRing + Field> {
+    coefficients: Vec<T>,
+    initial: Vec<T>,
+}
+
+impl<T: CRing + Field> LinearRecurrence<T> {
+    pub fn new(coefficients: Vec<T>, initial: Vec<T>) -> Self {
+        assert_eq!(coefficients.len(), initial.len(), "need d initial terms");
+        LinearRecurrence {
+            coefficients,
+            initial,
+        }
+    }
+
+    // 数列の先頭を満たす最短の漸化式
+    pub fn from_sequence(sequence: &[T]) -> Self {
+        let coefficients = berlekamp_massey(sequence);
+        let initial = sequence[..coefficients.len()].to_vec();
+        LinearRecurrence {
+            coefficients,
+            initial,
+        }
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    pub fn coefficients(&self) -> &[T] {
+        &self.coefficients
+    }
+
+    // 母関数 P(x) / Q(x) (Q(x) = 1 - Σ c_i x^i, deg P < d)
+    pub fn generating_function(&self) -> (Polynomial<T>, Polynomial<T>) {
+        let q = Polynomial::from(
+            std::iter::once(T::one())
+                .chain(self.coefficients.iter().map(|c| -c.clone()))
+                .collect::<Vec<_>>(),
+        );
+        let p = (Polynomial::from(self.initial.clone()) * q.clone()).prefix(self.order());
+        (p, q)
+    }
+
+    // a_n (O(M(d) log n))
+    pub fn nth(&self, n: u64) -> T {
+        let (p, q) = self.generating_function();
+        nth_term(&p, &q, n).unwrap()
+    }
+
+    // a_0, ..., a_(n-1)
+    pub fn terms(&self, n: usize) -> Vec<T> {
+        let mut terms: Vec<T> = self.initial.iter().take(n).cloned().collect();
+        for i in terms.len()..n {
+            let next = self
+                .coefficients
+                .iter()
+                .zip(terms[..i].iter().rev())
+                .fold(T::zero(), |acc, (c, a)| acc + c.clone() * a.clone());
+            terms.push(next);
+        }
+        terms
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::QQ;
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+    use rand::Rng;
+
+    type Mint = ModInt998244353;
+
+    fn mint(v: &[u32]) -> Vec<Mint> {
+        v.iter().map(|&x| Mint::from(x)).collect()
+    }
+
+    #[test]
+    fn berlekamp_massey_recovers() {
+        // Fibonacci
+        let fib = mint(&[0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+        assert_eq!(berlekamp_massey(&fib), mint(&[1, 1]));
+        assert_eq!(berlekamp_massey(&mint(&[0, 0, 0])), vec![]);
+        assert_eq!(berlekamp_massey(&mint(&[0, 0, 5, 0])), mint(&[0, 0, 5]));
+        let mut rng = rand::thread_rng();
+        for d in [1, 5, 50] {
+            let recurrence = LinearRecurrence::new(
+                (0..d).map(|_| Mint::from(rng.gen::<u32>())).collect(),
+                (0..d).map(|_| Mint::from(rng.gen::<u32>())).collect(),
+            );
+            let sequence = recurrence.terms(2 * d);
+            let found = LinearRecurrence::from_sequence(&sequence);
+            assert_eq!(found, recurrence);
+            assert_eq!(found.terms(3 * d), recurrence.terms(3 * d));
+        }
+        // 有理数体上: a_n = a_(n-1) / 2 + a_(n-2)
+        let half = QQ::new(1.into(), 2.into());
+        let recurrence = LinearRecurrence::new(
+            vec![half, QQ::from_integer(1.into())],
+            vec![QQ::from_integer(3.into()), QQ::from_integer((-1).into())],
+        );
+        let found = LinearRecurrence::from_sequence(&recurrence.terms(10));
+        assert_eq!(found, recurrence);
+    }
+
+    #[test]
+    fn nth() {
+        let mut rng = rand::thread_rng();
+        for d in [1, 3, 20] {
+            let recurrence = LinearRecurrence::new(
+                (0..d).map(|_| Mint::from(rng.gen::<u32>())).collect(),
+                (0..d).map(|_| Mint::from(rng.gen::<u32>())).collect(),
+            );
+            let terms = recurrence.terms(200);
+            for n in [0, 1, d, 99, 199] {
+                assert_eq!(recurrence.nth(n as u64), terms[n]);
+            }
+        }
+        // F(2n) = F(n) (2 F(n + 1) - F(n))
+        let fib = LinearRecurrence::new(mint(&[1, 1]), mint(&[0, 1]));
+        let n = 1_000_000_000_000_000_000u64 / 2;
+        let (a, b) = (fib.nth(n), fib.nth(n + 1));
+        assert_eq!(fib.nth(2 * n), a * (b * Mint::from(2) - a));
+        assert_eq!(fib.nth(90), Mint::from(2880067194370816120u64 % 998244353));
+    }
+
+    #[test]
+    fn rational_function() {
+        // 1 / (1 - x)^2 = Σ (n + 1) x^n
+        let p = Polynomial::from(mint(&[1]));
+        let q = Polynomial::from(mint(&[1, 998244351, 1]));
+        assert_eq!(nth_term(&p, &q, 10), Ok(Mint::from(11)));
+        assert_eq!(nth_term(&p, &q, 998244353), Ok(Mint::from(1)));
+        // 分子の次数が分母以上でもよい
+        let p = Polynomial::from(mint(&[0, 0, 0, 0, 0, 1]));
+        assert_eq!(nth_term(&p, &q, 4), Ok(Mint::from(0)));
+        assert_eq!(nth_term(&p, &q, 15), Ok(Mint::from(11)));
+        assert_eq!(
+            nth_term(&p, &Polynomial::from(mint(&[0, 1])), 3),
+            Err(PolynomialError::DivisionByZero)
+        );
+    }
+}
@@ -0,0 +1,577 @@
+use crate::algebraic::ring::convolution::{karatsuba, Convolution};
+use crate::algebraic::{CommutativeRing, Field, One, ScalarMul, ScalarPow, Zero};
+use crate::util::trait_alias;
+use std::error::Error;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{
+    Add, AddAssign, BitXor, BitXorAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg,
+    RangeToInclusive, Rem, RemAssign, Sub, SubAssign,
+};
+
+pub mod evaluation;
+pub mod finite_field;
+pub mod half_gcd;
+pub mod integer;
+pub mod linear_recurrence;
+pub mod power_series;
+pub mod resultant;
+
+pub use evaluation::lagrange_consecutive;
+use half_gcd::HALF_GCD_THRESHOLD;
+pub use linear_recurrence::{berlekamp_massey, nth_term, LinearRecurrence};
+pub use power_series::PowerSeries;
+
+// 係数は昇冪で末尾に 0 を持たない (零多項式は空)
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Polynomial<T: CRing>(Vec<T>);
+trait_alias! {CRing = CommutativeRing + Clone + Eq + Display + Convolution}
+trait_alias! {Analysis = Field + From<usize> + Clone + Copy + Eq + Display + Convolution}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolynomialError {
+    DivisionByZero,
+    DuplicatePoints,
+}
+
+impl Display for PolynomialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialError::DivisionByZero => write!(f, "division by the zero polynomial"),
+            PolynomialError::DuplicatePoints => write!(f, "interpolation points are not distinct"),
+        }
+    }
+}
+
+impl Error for PolynomialError {}
+
+impl<T: CRing> Polynomial<T> {
+    pub fn term(coeff: T, power: usize) -> Polynomial<T> {
+        let mut poly = vec![T::zero(); power];
+        poly.push(coeff);
+        Polynomial::from(poly)
+    }
+
+    // 不定元 (indeterminate)
+    #[inline]
+    pub fn x() -> Polynomial<T> {
+        Polynomial::term(T::one(), 1)
+    }
+
+    // 多項式の係数 (昇冪)
+    #[inline]
+    pub fn coeff(&self) -> Vec<T> {
+        self.0.clone()
+    }
+
+    // 次数 (零多項式は負の無限大として None)
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        self.0.len().checked_sub(1)
+    }
+
+    // 最高次の係数 (零多項式は 0)
+    pub fn leading_coefficient(&self) -> T {
+        self.0.last().cloned().unwrap_or_else(T::zero)
+    }
+
+    // mod x^n
+    pub fn prefix(&self, n: usize) -> Self {
+        Polynomial::from(self.0[..n.min(self.0.len())].to_vec())
+    }
+
+    fn scale(&self, c: &T) -> Self {
+        Polynomial::from(
+            self.0
+                .iter()
+                .map(|x| x.clone() * c.clone())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    // 代入 (Horner 法)
+    pub fn evaluate(&self, x: &T) -> T {
+        self.0
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    // 擬除算 lc(rhs)^(deg(self) - deg(rhs) + 1) self = q rhs + r
+    pub fn pseudo_div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialError> {
+        if rhs.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        let (n, m) = (self.0.len(), rhs.0.len());
+        if n < m {
+            return Ok((Polynomial::zero(), self.clone()));
+        }
+        let d = rhs.leading_coefficient();
+        let mut q = vec![T::zero(); n - m + 1];
+        let mut r = self.0.clone();
+        for i in (0..=n - m).rev() {
+            let c = r[i + m - 1].clone();
+            q.iter_mut().for_each(|x| *x *= d.clone());
+            r.iter_mut().for_each(|x| *x *= d.clone());
+            for j in 0..m {
+                r[i + j] -= c.clone() * rhs[j].clone();
+            }
+            q[i] = c;
+        }
+        r.truncate(m - 1);
+        Ok((Polynomial::from(q), Polynomial::from(r)))
+    }
+
+    pub fn pseudo_div(&self, rhs: &Self) -> Result<Self, PolynomialError> {
+        self.pseudo_div_rem(rhs).map(|(q, _)| q)
+    }
+
+    pub fn pseudo_rem(&self, rhs: &Self) -> Result<Self, PolynomialError> {
+        self.pseudo_div_rem(rhs).map(|(_, r)| r)
+    }
+
+    // 末尾の 0 を落とす
+    fn reduction(&mut self) {
+        while self.0.last().is_some_and(|x| x.is_zero()) {
+            self.0.pop();
+        }
+    }
+}
+
+impl<T: CRing + Field> Polynomial<T> {
+    // 商と余り
+    pub fn div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialError> {
+        if rhs.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        let (n, m) = (self.0.len(), rhs.0.len());
+        if n < m {
+            return Ok((Polynomial::zero(), self.clone()));
+        }
+        let inv = T::one() / rhs.leading_coefficient();
+        let mut q = vec![T::zero(); n - m + 1];
+        let mut r = self.0.clone();
+        for i in (0..=n - m).rev() {
+            let c = r[i + m - 1].clone() * inv.clone();
+            for j in 0..m {
+                r[i + j] -= c.clone() * rhs[j].clone();
+            }
+            q[i] = c;
+        }
+        r.truncate(m - 1);
+        Ok((Polynomial::from(q), Polynomial::from(r)))
+    }
+
+    // モニック多項式
+    pub fn monic(&self) -> Result<Self, PolynomialError> {
+        if self.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+        Ok(self.scale(&(T::one() / self.leading_coefficient())))
+    }
+
+    // モニックな最大公約数 (gcd(0, 0) = 0, 次数が大きければ half-GCD)
+    pub fn gcd(&self, rhs: &Self) -> Self {
+        if self.0.len().min(rhs.0.len()) > HALF_GCD_THRESHOLD {
+            let g = self.euclid(rhs).0;
+            return g.monic().unwrap_or(g);
+        }
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        while !b.is_zero() {
+            let r = a.div_rem(&b).unwrap().1;
+            (a, b) = (b, r);
+        }
+        a.monic().unwrap_or(a)
+    }
+
+    // (g, s, t) で self s + rhs t = g (g はモニックな最大公約数)
+    pub fn xgcd(&self, rhs: &Self) -> (Self, Self, Self) {
+        if self.0.len().min(rhs.0.len()) > HALF_GCD_THRESHOLD {
+            let (g, [[s, t], _], _) = self.euclid(rhs);
+            let inv = T::one() / g.leading_coefficient();
+            return (g.scale(&inv), s.scale(&inv), t.scale(&inv));
+        }
+        let (mut r0, mut r1) = (self.clone(), rhs.clone());
+        let (mut s0, mut s1) = (Polynomial::one(), Polynomial::zero());
+        let (mut t0, mut t1) = (Polynomial::zero(), Polynomial::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_rem(&r1).unwrap();
+            (r0, r1) = (r1, r);
+            let s = s0 - q.clone() * s1.clone();
+            (s0, s1) = (s1, s);
+            let t = t0 - q * t1.clone();
+            (t0, t1) = (t1, t);
+        }
+        if r0.is_zero() {
+            return (r0, Polynomial::zero(), Polynomial::zero());
+        }
+        let inv = T::one() / r0.leading_coefficient();
+        (r0.scale(&inv), s0.scale(&inv), t0.scale(&inv))
+    }
+
+    // modulus を法とする逆元 (互いに素でなければ None)
+    pub fn mod_inverse(&self, modulus: &Self) -> Option<Self> {
+        let a = self.div_rem(modulus).ok()?.1;
+        let (g, s, _) = a.xgcd(modulus);
+        g.is_one().then(|| s.div_rem(modulus).unwrap().1)
+    }
+}
+
+impl<T: Analysis> Polynomial<T> {
+    pub fn diff(&self) -> Self {
+        Polynomial::from(
+            (1..self.0.len())
+                .map(|i| self[i] * i.into())
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    pub fn integral(&self) -> Self {
+        let mut ret = vec![T::zero(); self.0.len() + 1];
+        for i in 0..self.0.len() {
+            ret[i + 1] = self[i] / (i + 1).into();
+        }
+        Polynomial::from(ret)
+    }
+}
+
+impl<T: CRing> Zero for Polynomial<T> {
+    fn zero() -> Self {
+        Polynomial(Vec::new())
+    }
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<T: CRing> One for Polynomial<T> {
+    fn one() -> Self {
+        Polynomial::from(vec![T::one()])
+    }
+    fn is_one(&self) -> bool {
+        self.0.len() == 1 && self.0[0].is_one()
+    }
+}
+
+impl<T: CRing> AddAssign for Polynomial<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        if self.0.len() < rhs.0.len() {
+            self.0.resize(rhs.0.len(), T::zero());
+        }
+        for (i, x) in rhs.0.into_iter().enumerate() {
+            self[i] += x;
+        }
+        self.reduction();
+    }
+}
+
+impl<T: CRing> SubAssign for Polynomial<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        if self.0.len() < rhs.0.len() {
+            self.0.resize(rhs.0.len(), T::zero());
+        }
+        for (i, x) in rhs.0.into_iter().enumerate() {
+            self[i] -= x;
+        }
+        self.reduction();
+    }
+}
+
+impl<T: CRing> MulAssign for Polynomial<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<T: CRing + Field> DivAssign for Polynomial<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).unwrap_or_else(|e| panic!("{}", e)).0;
+    }
+}
+
+impl<T: CRing + Field> RemAssign for Polynomial<T> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).unwrap_or_else(|e| panic!("{}", e)).1;
+    }
+}
+
+impl<T: CRing> BitXorAssign<usize> for Polynomial<T> {
+    fn bitxor_assign(&mut self, rhs: usize) {
+        *self = self.scalar_pow(rhs);
+    }
+}
+
+impl<T: CRing> Add for Polynomial<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp += rhs;
+        tmp
+    }
+}
+
+impl<T: CRing> Neg for Polynomial<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Polynomial(self.0.into_iter().map(|x| -x).collect())
+    }
+}
+
+impl<T: CRing> Sub for Polynomial<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp -= rhs;
+        tmp
+    }
+}
+
+impl<T: CRing> Mul for Polynomial<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Polynomial::zero();
+        }
+        Polynomial::from(T::convolution(&self.0, &rhs.0))
+    }
+}
+
+impl<T: CRing> Convolution for Polynomial<T> {
+    fn convolution(lhs: &[Self], rhs: &[Self]) -> Vec<Self> {
+        karatsuba(lhs, rhs)
+    }
+}
+
+impl<T: CRing> ScalarMul for Polynomial<T> {
+    fn scalar_mul(&self, rhs: usize) -> Self {
+        Polynomial::from(self.0.iter().map(|x| x.scalar_mul(rhs)).collect::<Vec<_>>())
+    }
+}
+
+impl<T: CRing> ScalarPow for Polynomial<T> {
+    fn scalar_pow(&self, mut e: usize) -> Self {
+        let mut result = Self::one();
+        let mut cur = self.clone();
+        while e > 0 {
+            if e & 1 == 1 {
+                result *= cur.clone();
+            }
+            e >>= 1;
+            cur *= cur.clone();
+        }
+        result
+    }
+}
+
+impl<T: CRing + Field> Div for Polynomial<T> {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp /= rhs;
+        tmp
+    }
+}
+
+impl<T: CRing + Field> Rem for Polynomial<T> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp %= rhs;
+        tmp
+    }
+}
+
+impl<T: CRing> BitXor<usize> for Polynomial<T> {
+    type Output = Self;
+    fn bitxor(self, rhs: usize) -> Self {
+        let mut tmp = self.clone();
+        tmp ^= rhs;
+        tmp
+    }
+}
+
+// 単項式 c x^i (c = 0 なら何も書かない)
+fn write_term<T: CRing>(f: &mut Formatter<'_>, c: &T, i: usize) -> fmt::Result {
+    match i {
+        _ if c.is_zero() => Ok(()),
+        0 => write!(f, "{}", c),
+        1 if c.is_one() => write!(f, "x"),
+        _ if c.is_one() => write!(f, "x^{}", i),
+        1 => write!(f, "{}x", c),
+        _ => write!(f, "{}x^{}", c, i),
+    }
+}
+
+impl<T: CRing> Display for Polynomial<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+        for i in (0..self.0.len()).rev() {
+            if i + 1 != self.0.len() && !self[i].is_zero() {
+                write!(f, " + ")?;
+            }
+            write_term(f, &self[i], i)?;
+        }
+        Ok(())
+    }
+}
+
+// 係数
+impl<T: CRing> From<Vec<T>> for Polynomial<T> {
+    fn from(coeff: Vec<T>) -> Self {
+        let mut poly = Self(coeff);
+        poly.reduction();
+        poly
+    }
+}
+
+impl<T: CRing> Index<usize> for Polynomial<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T: CRing> Index<RangeToInclusive<usize>> for Polynomial<T> {
+    type Output = [T];
+    #[inline]
+    fn index(&self, index: RangeToInclusive<usize>) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<T: CRing> IndexMut<usize> for Polynomial<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.0[index]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::{QQ, ZZ};
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+    use rand::Rng;
+
+    type Mint = ModInt998244353;
+
+    fn random_poly(
+        rng: &mut rand::rngs::ThreadRng,
+        len: std::ops::Range<usize>,
+    ) -> Polynomial<Mint> {
+        let len = rng.gen_range(len);
+        Polynomial::from(
+            (0..len)
+                .map(|_| Mint::from(rng.gen::<u32>()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn qq(coeff: &[i64]) -> Polynomial<QQ> {
+        Polynomial::from(
+            coeff
+                .iter()
+                .map(|&x| QQ::from_integer(x.into()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn degree_and_display() {
+        assert_eq!(Polynomial::<QQ>::zero().degree(), None);
+        assert_eq!(qq(&[0, 0]), Polynomial::zero());
+        assert_eq!(qq(&[5]).degree(), Some(0));
+        assert_eq!(qq(&[1, 0, 3, 0]).degree(), Some(2));
+        assert!(Polynomial::<QQ>::zero().degree() < qq(&[5]).degree());
+        assert_eq!(qq(&[1, -1]) + qq(&[-1, 1]), Polynomial::zero());
+        assert_eq!((qq(&[2, 1]) * Polynomial::zero()).degree(), None);
+        assert_eq!(format!("{}", qq(&[1, 0, 3, 1])), "x^3 + 3x^2 + 1");
+        assert_eq!(format!("{}", Polynomial::<QQ>::zero()), "0");
+        assert_eq!(format!("{}", qq(&[2, 4]) ^ 2), "16x^2 + 16x + 4");
+    }
+
+    #[test]
+    fn div_rem() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..200 {
+            let a = random_poly(&mut rng, 0..40);
+            let b = random_poly(&mut rng, 1..20);
+            if b.is_zero() {
+                continue;
+            }
+            let (q, r) = a.div_rem(&b).unwrap();
+            assert_eq!(q.clone() * b.clone() + r.clone(), a);
+            assert!(r.degree() < b.degree());
+            assert_eq!(a.clone() / b.clone(), q);
+            assert_eq!(a % b, r);
+        }
+        // (x^3 - 2x + 1) = (x - 1)(x^2 + x - 1)
+        let (q, r) = qq(&[1, -2, 0, 1]).div_rem(&qq(&[-1, 1])).unwrap();
+        assert_eq!((q, r), (qq(&[-1, 1, 1]), Polynomial::zero()));
+        assert_eq!(
+            qq(&[1, 2]).div_rem(&Polynomial::zero()),
+            Err(PolynomialError::DivisionByZero)
+        );
+        assert_eq!(
+            Polynomial::<QQ>::zero().monic(),
+            Err(PolynomialError::DivisionByZero)
+        );
+        assert_eq!(
+            qq(&[2, 4]).monic().unwrap(),
+            Polynomial::from(vec![QQ::new(1.into(), 2.into()), QQ::one()])
+        );
+    }
+
+    #[test]
+    fn pseudo_division() {
+        let zz = |coeff: &[i64]| {
+            Polynomial::from(coeff.iter().map(|&x| ZZ::from(x)).collect::<Vec<_>>())
+        };
+        let a = zz(&[1, 0, -3, 5, 2]);
+        let b = zz(&[-4, 0, 3]);
+        let (q, r) = a.pseudo_div_rem(&b).unwrap();
+        // 3^3 a = q b + r
+        let lc = zz(&[27]);
+        assert_eq!(lc * a.clone(), q.clone() * b.clone() + r.clone());
+        assert!(r.degree() < b.degree());
+        assert_eq!(a.pseudo_div(&b), Ok(q));
+        assert_eq!(a.pseudo_rem(&b), Ok(r));
+        assert_eq!(b.pseudo_div_rem(&a), Ok((Polynomial::zero(), b.clone())));
+        assert_eq!(
+            a.pseudo_rem(&Polynomial::zero()),
+            Err(PolynomialError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn gcd() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let g = random_poly(&mut rng, 1..10).monic().unwrap();
+            let a = random_poly(&mut rng, 1..20) * g.clone();
+            let b = random_poly(&mut rng, 1..20) * g.clone();
+            let d = a.gcd(&b);
+            assert!((a.clone() % d.clone()).is_zero());
+            assert!((b.clone() % d.clone()).is_zero());
+            assert!((d.clone() % g).is_zero());
+            let (e, s, t) = a.xgcd(&b);
+            assert_eq!(e, d);
+            assert_eq!(a * s + b * t, d);
+        }
+        // gcd(x^2 - 1, x^2 + 2x + 1) = x + 1
+        let a = qq(&[-1, 0, 1]);
+        let b = qq(&[1, 2, 1]);
+        assert_eq!(a.gcd(&b), qq(&[1, 1]));
+        let (g, s, t) = a.xgcd(&b);
+        assert_eq!(g, qq(&[1, 1]));
+        assert_eq!(a.clone() * s + b * t, g);
+        assert_eq!(a.gcd(&Polynomial::zero()), qq(&[-1, 0, 1]));
+        assert_eq!(
+            Polynomial::<QQ>::zero().xgcd(&Polynomial::zero()),
+            (Polynomial::zero(), Polynomial::zero(), Polynomial::zero())
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use super::{write_term, Analysis, CRing, Polynomial};
+use crate::algebraic::{One, Sqrt};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// 精度 n の冪級数 a_0 + a_1 x + ... + a_{n-1} x^{n-1} + O(x^n)
+// 係数はちょうど n 個持つ
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PowerSeries<T: CRing>(Vec<T>);
+
+impl<T: CRing> PowerSeries<T> {
+    // 係数を precision 個に切り詰める (足りなければ 0 を補う)
+    pub fn new(mut coeff: Vec<T>, precision: usize) -> Self {
+        coeff.resize(precision, T::zero());
+        PowerSeries(coeff)
+    }
+
+    pub fn from_polynomial(f: &Polynomial<T>, precision: usize) -> Self {
+        PowerSeries::new(f.prefix(precision).0, precision)
+    }
+
+    // O(x^n) を落とした多項式
+    pub fn polynomial(&self) -> Polynomial<T> {
+        Polynomial::from(self.0.clone())
+    }
+
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.0.len()
+    }
+
+    #[inline]
+    pub fn coeff(&self) -> Vec<T> {
+        self.0.clone()
+    }
+
+    // 0 でない最小の次数 (分かっている係数がすべて 0 なら None)
+    pub fn valuation(&self) -> Option<usize> {
+        self.0.iter().position(|x| !x.is_zero())
+    }
+
+    // 精度を n 以下に落とす
+    pub fn truncate(&self, n: usize) -> Self {
+        PowerSeries(self.0[..n.min(self.0.len())].to_vec())
+    }
+
+    fn scale(&self, c: &T) -> Self {
+        PowerSeries(self.0.iter().map(|x| x.clone() * c.clone()).collect())
+    }
+
+    // x^d で割る (d <= valuation を仮定する)
+    fn shift_down(&self, d: usize) -> Self {
+        PowerSeries(self.0[d..].to_vec())
+    }
+
+    // x^d を掛ける
+    fn shift_up(&self, d: usize) -> Self {
+        let mut coeff = vec![T::zero(); d];
+        coeff.extend(self.0.iter().cloned());
+        PowerSeries(coeff)
+    }
+}
+
+impl<T: Analysis> PowerSeries<T> {
+    pub fn diff(&self) -> Self {
+        PowerSeries((1..self.0.len()).map(|i| self[i] * i.into()).collect())
+    }
+
+    pub fn integral(&self) -> Self {
+        let mut coeff = vec![T::zero()];
+        coeff.extend((0..self.0.len()).map(|i| self[i] / (i + 1).into()));
+        PowerSeries(coeff)
+    }
+
+    // 1 / f (f(0) が 0 なら None)
+    pub fn inv(&self) -> Option<Self> {
+        let n = self.precision();
+        if n == 0 {
+            return Some(self.clone());
+        }
+        if self[0].is_zero() {
+            return None;
+        }
+        // g ← g (2 - f g) mod x^(2m)
+        let f = self.polynomial();
+        let mut g = Polynomial::from(vec![T::one() / self[0]]);
+        let mut m = 1;
+        while m < n {
+            m = (2 * m).min(n);
+            let h = (f.prefix(m) * g.clone()).prefix(m);
+            g = (g.clone() * (Polynomial::from(vec![T::from(2)]) - h)).prefix(m);
+        }
+        Some(PowerSeries::from_polynomial(&g, n))
+    }
+
+    // log f (f(0) = 1 でなければ None)
+    pub fn log(&self) -> Option<Self> {
+        if self.precision() == 0 {
+            return Some(self.clone());
+        }
+        if !self[0].is_one() {
+            return None;
+        }
+        Some((self.diff() * self.inv()?).integral())
+    }
+
+    // exp f (f(0) = 0 でなければ None)
+    pub fn exp(&self) -> Option<Self> {
+        let n = self.precision();
+        if n == 0 {
+            return Some(self.clone());
+        }
+        if !self[0].is_zero() {
+            return None;
+        }
+        // g ← g (1 - log g + f) mod x^(2m)
+        let f = self.polynomial();
+        let mut g = Polynomial::one();
+        let mut m = 1;
+        while m < n {
+            m = (2 * m).min(n);
+            let log = PowerSeries::from_polynomial(&g, m).log().unwrap();
+            let h = f.prefix(m) - log.polynomial() + Polynomial::one();
+            g = (g.clone() * h).prefix(m);
+        }
+        Some(PowerSeries::from_polynomial(&g, n))
+    }
+
+    // f^k (精度は f と同じ)
+    pub fn pow(&self, k: usize) -> Self {
+        let n = self.precision();
+        if k == 0 {
+            return PowerSeries::new(vec![T::one()], n);
+        }
+        // f = c x^d (1 + g) と分解する
+        let Some(d) = self.valuation() else {
+            return self.clone();
+        };
+        match d.checked_mul(k) {
+            Some(shift) if shift < n => {
+                let c = self[d];
+                let g = self.shift_down(d).scale(&(T::one() / c));
+                let h = g.log().unwrap().scale(&T::from(k)).exp().unwrap();
+                h.scale(&c.scalar_pow(k)).shift_up(shift).truncate(n)
+            }
+            _ => PowerSeries::new(Vec::new(), n),
+        }
+    }
+}
+
+impl<T: Analysis + Sqrt> PowerSeries<T> {
+    // 平方根 (存在しなければ None, f = x^d h なら精度は n - d / 2)
+    pub fn sqrt(&self) -> Option<Self> {
+        let n = self.precision();
+        let Some(d) = self.valuation() else {
+            return Some(PowerSeries::new(Vec::new(), n.div_ceil(2)));
+        };
+        if d % 2 == 1 {
+            return None;
+        }
+        // f = x^d h として g ← (g + h / g) / 2 mod x^(2m)
+        let h = self.shift_down(d);
+        let len = h.precision();
+        let mut g = Polynomial::from(vec![h[0].sqrt()?]);
+        let half = T::one() / T::from(2);
+        let mut m = 1;
+        while m < len {
+            m = (2 * m).min(len);
+            let inv = PowerSeries::from_polynomial(&g, m).inv().unwrap();
+            let q = (h.truncate(m) * inv).polynomial();
+            g = (g + q).scale(&half);
+        }
+        Some(PowerSeries::from_polynomial(&g, len).shift_up(d / 2))
+    }
+}
+
+impl<T: CRing> From<PowerSeries<T>> for Polynomial<T> {
+    fn from(f: PowerSeries<T>) -> Self {
+        Polynomial::from(f.0)
+    }
+}
+
+impl<T: CRing> AddAssign for PowerSeries<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.truncate(rhs.0.len());
+        for (i, x) in self.0.iter_mut().enumerate() {
+            *x += rhs[i].clone();
+        }
+    }
+}
+
+impl<T: CRing> SubAssign for PowerSeries<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.truncate(rhs.0.len());
+        for (i, x) in self.0.iter_mut().enumerate() {
+            *x -= rhs[i].clone();
+        }
+    }
+}
+
+// (a + O(x^n)) (b + O(x^m)) = ab + O(x^min(n + v(b), m + v(a)))
+impl<T: CRing> MulAssign for PowerSeries<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        let (n, m) = (self.precision(), rhs.precision());
+        let (va, vb) = (self.valuation().unwrap_or(n), rhs.valuation().unwrap_or(m));
+        let precision = (n + vb).min(m + va);
+        let product = if self.0.is_empty() || rhs.0.is_empty() {
+            Vec::new()
+        } else {
+            T::convolution(&self.0, &rhs.0)
+        };
+        *self = PowerSeries::new(product, precision);
+    }
+}
+
+impl<T: CRing> Add for PowerSeries<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp += rhs;
+        tmp
+    }
+}
+
+impl<T: CRing> Sub for PowerSeries<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp -= rhs;
+        tmp
+    }
+}
+
+impl<T: CRing> Neg for PowerSeries<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        PowerSeries(self.0.into_iter().map(|x| -x).collect())
+    }
+}
+
+impl<T: CRing> Mul for PowerSeries<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let mut tmp = self.clone();
+        tmp *= rhs;
+        tmp
+    }
+}
+
+// 昇冪で書く (例: 1 + 2x + O(x^3))
+impl<T: CRing> Display for PowerSeries<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.0.iter().enumerate() {
+            write_term(f, c, i)?;
+            if !c.is_zero() {
+                write!(f, " + ")?;
+            }
+        }
+        match self.precision() {
+            0 => write!(f, "O(1)"),
+            1 => write!(f, "O(x)"),
+            n => write!(f, "O(x^{})", n),
+        }
+    }
+}
+
+impl<T: CRing> Index<usize> for PowerSeries<T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::QQ;
+    use crate::algebraic::ring::integer_mod::static_mod_int::ModInt998244353;
+    use crate::algebraic::Zero;
+    use rand::Rng;
+
+    type Mint = ModInt998244353;
+
+    fn random_series(rng: &mut rand::rngs::ThreadRng, precision: usize) -> PowerSeries<Mint> {
+        PowerSeries::new(
+            (0..precision)
+                .map(|_| Mint::from(rng.gen::<u32>()))
+                .collect(),
+            precision,
+        )
+    }
+
+    fn qq(coeff: &[i64], precision: usize) -> PowerSeries<QQ> {
+        let coeff = coeff.iter().map(|&x| QQ::from_integer(x.into())).collect();
+        PowerSeries::new(coeff, precision)
+    }
+
+    #[test]
+    fn precision() {
+        let f = qq(&[1, 2, 3], 5);
+        let g = qq(&[0, 0, 1], 4);
+        assert_eq!((f.clone() + g.clone()).precision(), 4);
+        assert_eq!(
+            (f.clone() - g.clone()).coeff(),
+            qq(&[1, 2, 2, 0], 4).coeff()
+        );
+        // (1 + O(x^5)) (x^2 + O(x^4)) = x^2 + O(x^4)
+        assert_eq!(f.clone() * g.clone(), qq(&[0, 0, 1, 2], 4));
+        // (x^2 + O(x^4))^2 = x^4 + O(x^6)
+        assert_eq!(g.clone() * g.clone(), qq(&[0, 0, 0, 0, 1], 6));
+        assert_eq!((qq(&[], 3) * qq(&[], 2)).precision(), 5);
+        assert_eq!(f.truncate(2), qq(&[1, 2], 2));
+        assert_eq!(f.truncate(10), f);
+        assert_eq!(Polynomial::from(f.clone()), Polynomial::from(f.coeff()));
+        let p = Polynomial::from(vec![QQ::one(); 4]);
+        assert_eq!(
+            PowerSeries::from_polynomial(&p, 2).polynomial(),
+            p.prefix(2)
+        );
+        assert_eq!(g.valuation(), Some(2));
+        assert_eq!(qq(&[], 3).valuation(), None);
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", qq(&[1, 2, 0, 1], 5)), "1 + 2x + x^3 + O(x^5)");
+        assert_eq!(format!("{}", qq(&[0, -1], 2)), "-1x + O(x^2)");
+        assert_eq!(format!("{}", qq(&[3], 1)), "3 + O(x)");
+        assert_eq!(format!("{}", qq(&[], 0)), "O(1)");
+    }
+
+    #[test]
+    fn newton() {
+        let mut rng = rand::thread_rng();
+        for n in [0, 1, 2, 3, 10, 100, 257] {
+            let mut f = random_series(&mut rng, n);
+            if n > 0 {
+                f.0[0] = Mint::one();
+            }
+            let g = f.inv().unwrap();
+            assert_eq!(g.precision(), n);
+            assert_eq!(f.clone() * g, PowerSeries::new(vec![Mint::one()], n));
+            assert_eq!(f.log().unwrap().exp().unwrap(), f);
+            let mut h = random_series(&mut rng, n);
+            if n > 0 {
+                h.0[0] = Mint::zero();
+            }
+            assert_eq!(h.exp().unwrap().log().unwrap(), h);
+        }
+        // exp(x) = Σ x^k / k!
+        let x = PowerSeries::from_polynomial(&Polynomial::<Mint>::x(), 10);
+        let e = x.exp().unwrap();
+        let mut factorial = Mint::one();
+        for k in 0..10 {
+            assert_eq!(e[k] * factorial, Mint::one());
+            factorial *= Mint::from(k + 1);
+        }
+        assert_eq!(x.inv(), None);
+        assert_eq!(x.log(), None);
+        assert_eq!(e.exp(), None);
+    }
+
+    #[test]
+    fn pow_and_sqrt() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let shift = rng.gen_range(0..5);
+            let n: usize = rng.gen_range(0..60);
+            let f = random_series(&mut rng, n.saturating_sub(shift)).shift_up(shift);
+            let f = f.truncate(n);
+            for k in [0, 1, 2, 5, 13] {
+                let expected = (0..k).fold(PowerSeries::new(vec![Mint::one()], n), |acc, _| {
+                    (acc * f.clone()).truncate(n)
+                });
+                assert_eq!(f.pow(k), expected);
+            }
+            if f.valuation().is_none() {
+                continue;
+            }
+            let square = f.clone() * f.clone();
+            let g = square.sqrt().unwrap();
+            assert_eq!(g.precision(), square.precision() - shift);
+            assert_eq!(g.clone() * g, square);
+        }
+        let x = PowerSeries::from_polynomial(&Polynomial::<Mint>::x(), 10);
+        assert_eq!(x.pow(usize::MAX), PowerSeries::new(Vec::new(), 10));
+        assert_eq!(x.sqrt(), None);
+        // 3 は mod 998244353 で平方非剰余
+        assert_eq!(PowerSeries::new(vec![Mint::from(3)], 10).sqrt(), None);
+        assert_eq!(
+            PowerSeries::<Mint>::new(Vec::new(), 9).sqrt(),
+            Some(PowerSeries::new(Vec::new(), 5))
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use super::{CRing, Polynomial};
+use crate::algebraic::Zero;
+use std::ops::Div;
+
+// 行列式 (Berkowitz, 除算を使わず O(n^4))
+fn determinant<T: CRing>(a: &[Vec<T>]) -> T {
+    let n = a.len();
+    // v は左上 r × r 小行列の特性多項式 det(xI - A) の係数 (降冪)
+    let mut v = vec![T::one()];
+    for r in 0..n {
+        // t = (1, -a_rr, -R C, -R A C, ..., -R A^(r-1) C)
+        let mut t = vec![T::one(), -a[r][r].clone()];
+        let mut c: Vec<T> = (0..r).map(|i| a[i][r].clone()).collect();
+        for _ in 0..r {
+            let rc = (0..r).fold(T::zero(), |acc, j| acc + a[r][j].clone() * c[j].clone());
+            t.push(-rc);
+            c = (0..r)
+                .map(|i| (0..r).fold(T::zero(), |acc, j| acc + a[i][j].clone() * c[j].clone()))
+                .collect();
+        }
+        v = (0..r + 2)
+            .map(|i| (0..=i.min(r)).fold(T::zero(), |acc, j| acc + t[i - j].clone() * v[j].clone()))
+            .collect();
+    }
+    // v[n] = det(-A)
+    if n.is_multiple_of(2) {
+        v[n].clone()
+    } else {
+        -v[n].clone()
+    }
+}
+
+impl<T: CRing> Polynomial<T> {
+    // Sylvester 行列 (deg rhs 行の self と deg self 行の rhs)
+    fn sylvester_matrix(&self, rhs: &Self) -> Vec<Vec<T>> {
+        let (n, m) = (self.0.len() - 1, rhs.0.len() - 1);
+        let mut matrix = vec![vec![T::zero(); n + m]; n + m];
+        for i in 0..m {
+            for (j, c) in self.0.iter().rev().enumerate() {
+                matrix[i][i + j] = c.clone();
+            }
+        }
+        for i in 0..n {
+            for (j, c) in rhs.0.iter().rev().enumerate() {
+                matrix[m + i][i + j] = c.clone();
+            }
+        }
+        matrix
+    }
+
+    // 終結式 (Sylvester 行列の行列式, 除算を使わないので任意の可換環で使える)
+    pub fn resultant(&self, rhs: &Self) -> T {
+        if self.is_zero() || rhs.is_zero() {
+            return T::zero();
+        }
+        determinant(&self.sylvester_matrix(rhs))
+    }
+
+    // 判別式 (-1)^(n(n-1)/2) res(f, f') / lc(f) (次数は 1 以上)
+    pub fn discriminant(&self) -> T {
+        let n = self
+            .degree()
+            .filter(|&n| n >= 1)
+            .expect("degree must be positive");
+        let df = Polynomial::from((1..=n).map(|i| self[i].scalar_mul(i)).collect::<Vec<_>>());
+        // f' の先頭の行から f の先頭の行の n 倍を引くと 1 列目は lc(f) だけになるので,
+        // lc(f) で割る代わりにその余因子を取る
+        let mut matrix = self.sylvester_matrix(&Polynomial(
+            df.0.iter()
+                .cloned()
+                .chain((df.0.len()..n).map(|_| T::zero()))
+                .collect(),
+        ));
+        let first = matrix[0].clone();
+        for (x, y) in matrix[n - 1].iter_mut().zip(first) {
+            *x -= y.scalar_mul(n);
+        }
+        let minor: Vec<Vec<T>> = matrix[1..].iter().map(|row| row[1..].to_vec()).collect();
+        let det = determinant(&minor);
+        if (n * (n - 1) / 2).is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+}
+
+impl<T: CRing + Div<Output = T>> Polynomial<T> {
+    // 部分終結式擬剰余列 (係数の除算は整域での割り切れる除算)
+    pub fn subresultant_prs(&self, rhs: &Self) -> Vec<Self> {
+        let (a, b) = if self.degree() >= rhs.degree() {
+            (self.clone(), rhs.clone())
+        } else {
+            (rhs.clone(), self.clone())
+        };
+        let mut sequence = vec![a];
+        if b.is_zero() {
+            return sequence;
+        }
+        sequence.push(b);
+        let degree = |f: &Self| f.0.len() - 1;
+        let mut d = degree(&sequence[0]) - degree(&sequence[1]);
+        let mut psi = -T::one();
+        let mut beta = if d.is_multiple_of(2) {
+            -T::one()
+        } else {
+            T::one()
+        };
+        loop {
+            let (prev, cur) = (&sequence[sequence.len() - 2], &sequence[sequence.len() - 1]);
+            let r = prev.pseudo_rem(cur).unwrap();
+            if r.is_zero() {
+                return sequence;
+            }
+            let next = Polynomial::from(
+                r.0.into_iter()
+                    .map(|x| x / beta.clone())
+                    .collect::<Vec<_>>(),
+            );
+            // ψ ← (-c)^d / ψ^(d-1), β ← -c ψ^d' (c = lc(cur), d' は次の次数差)
+            let c = cur.leading_coefficient();
+            psi = if d == 0 {
+                psi
+            } else {
+                (-c.clone()).scalar_pow(d) / psi.scalar_pow(d - 1)
+            };
+            d = degree(cur) - degree(&next);
+            beta = -c * psi.scalar_pow(d);
+            sequence.push(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::integer::{QQ, ZZ};
+    use crate::algebraic::ring::integer_mod::IntegerModRing;
+    use crate::algebraic::One;
+    use rand::Rng;
+
+    fn zz(coeff: &[i64]) -> Polynomial<ZZ> {
+        Polynomial::from(coeff.iter().map(|&c| ZZ::from(c)).collect::<Vec<_>>())
+    }
+
+    fn to_qq(f: &Polynomial<ZZ>) -> Polynomial<QQ> {
+        Polynomial::from(
+            f.0.iter()
+                .map(|c| QQ::from_integer(c.clone()))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn resultant_and_discriminant() {
+        // res(x^2 - 1, x - 2) = (1 - 2)(-1 - 2) = 3
+        assert_eq!(zz(&[-1, 0, 1]).resultant(&zz(&[-2, 1])), ZZ::from(3));
+        assert_eq!(zz(&[-2, 1]).resultant(&zz(&[-1, 0, 1])), ZZ::from(3));
+        // 共通根を持てば 0
+        assert_eq!(zz(&[-1, 0, 1]).resultant(&zz(&[1, 1])), ZZ::zero());
+        assert_eq!(zz(&[3]).resultant(&zz(&[1, 2, 3])), ZZ::from(9));
+        assert_eq!(zz(&[3]).resultant(&zz(&[5])), ZZ::one());
+        assert_eq!(zz(&[1, 2]).resultant(&Polynomial::zero()), ZZ::zero());
+        // b^2 - 4ac, -4p^3 - 27q^2
+        assert_eq!(zz(&[3, 5, 2]).discriminant(), ZZ::from(25 - 24));
+        assert_eq!(zz(&[7, 2, 0, 1]).discriminant(), ZZ::from(-4 * 8 - 27 * 49));
+        assert_eq!(zz(&[5, 3]).discriminant(), ZZ::one());
+        assert_eq!((zz(&[1, 1]) ^ 2).discriminant(), ZZ::zero());
+        // 整域でない Z/6Z でも計算できる
+        let ring = IntegerModRing::new(6.into());
+        let f = Polynomial::from(vec![ring.elem(2.into()), ring.elem(3.into()), ring.one()]);
+        let g = Polynomial::from(vec![ring.elem(5.into()), ring.one()]);
+        // f(-5) = 25 - 15 + 2 = 12 ≡ 0
+        assert_eq!(f.resultant(&g), ring.zero());
+    }
+
+    #[test]
+    fn random_resultant() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..30 {
+            let f = zz(&(0..rng.gen_range(1..7))
+                .map(|_| rng.gen_range(-5..6))
+                .collect::<Vec<_>>());
+            let g = zz(&(0..rng.gen_range(1..7))
+                .map(|_| rng.gen_range(-5..6))
+                .collect::<Vec<_>>());
+            if f.is_zero() || g.is_zero() {
+                continue;
+            }
+            let res = f.resultant(&g);
+            // res(f, g) = (-1)^(nm) res(g, f), res(f, gh) = res(f, g) res(f, h)
+            let (n, m) = (f.degree().unwrap(), g.degree().unwrap());
+            let sign = if n * m % 2 == 0 {
+                ZZ::one()
+            } else {
+                -ZZ::one()
+            };
+            assert_eq!(g.resultant(&f), sign * &res);
+            let h = zz(&[rng.gen_range(-5..6), 1]);
+            assert_eq!(
+                f.resultant(&(g.clone() * h.clone())),
+                &res * f.resultant(&h)
+            );
+            // 0 になるのは共通因子を持つとき
+            assert_eq!(res.is_zero(), to_qq(&f).gcd(&to_qq(&g)).degree() > Some(0));
+            if n >= 1 {
+                // disc(f) lc(f) = (-1)^(n(n-1)/2) res(f, f')
+                let df = zz(&(1..=n)
+                    .map(|i| i64::try_from(&f[i]).unwrap() * i as i64)
+                    .collect::<Vec<_>>());
+                let sign = if n * (n - 1) / 2 % 2 == 0 { 1 } else { -1 };
+                assert_eq!(
+                    f.discriminant() * f.leading_coefficient() * sign,
+                    f.resultant(&df)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn subresultant() {
+        // Knuth の例
+        let a = zz(&[-5, 2, 8, -3, -3, 0, 1, 0, 1]);
+        let b = zz(&[21, -9, -4, 0, 5, 0, 3]);
+        let sequence = a.subresultant_prs(&b);
+        assert_eq!(
+            sequence[2..],
+            [
+                zz(&[9, 0, -3, 0, 15]),
+                zz(&[-245, 125, 65]),
+                zz(&[-12300, 9326]),
+                zz(&[260708]),
+            ]
+        );
+        let mut rng = rand::thread_rng();
+        for _ in 0..30 {
+            let g = zz(&[rng.gen_range(-3..4), rng.gen_range(-3..4), 1]);
+            let f = zz(&(0..6).map(|_| rng.gen_range(-9..10)).collect::<Vec<_>>()) * g.clone();
+            let h = zz(&(0..5).map(|_| rng.gen_range(-9..10)).collect::<Vec<_>>()) * g.clone();
+            let sequence = f.subresultant_prs(&h);
+            // 各項は Euclid の剰余列の定数倍で, 最後の項は gcd の定数倍
+            let last = to_qq(sequence.last().unwrap());
+            let gcd = to_qq(&f).gcd(&to_qq(&h));
+            assert_eq!(last.monic().unwrap(), gcd);
+            for w in sequence.windows(3) {
+                let r = to_qq(&w[0]).div_rem(&to_qq(&w[1])).unwrap().1;
+                assert_eq!(r.monic().ok(), to_qq(&w[2]).monic().ok());
+            }
+        }
+    }
+}
@@ -1,16 +1,30 @@
-use crate::algebraic::{One, Zero};
-use std::ops::{Add, AddAssign, Mul};
+use crate::algebraic::{One, ScalarMul, Zero};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Mul, MulAssign};
 
 // Tropical semi-ring
-#[derive(Clone, Copy)]
+// 零元は -∞ (i64::MIN) で, 積 (整数の和) は飽和させて -∞ を吸収元にする
+// i64::MAX は +∞ として扱い, 有限の値を掛けても +∞ のまま (-∞ を掛けたときだけ -∞)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct MaxPlusSemiring(i64);
 
+impl MaxPlusSemiring {
+    pub fn new(v: i64) -> Self {
+        Self(v)
+    }
+    // -∞ なら None (+∞ は Some(i64::MAX))
+    pub fn value(&self) -> Option<i64> {
+        (!self.is_zero()).then_some(self.0)
+    }
+}
+
 impl Zero for MaxPlusSemiring {
     fn zero() -> Self {
-        Self(-1 << 60)
+        Self(i64::MIN)
     }
     fn is_zero(&self) -> bool {
-        self.0 <= -1 << 60
+        self.0 == i64::MIN
     }
 }
 
@@ -27,6 +41,17 @@ impl AddAssign for MaxPlusSemiring {
     }
 }
 
+// 和は冪等
+impl ScalarMul for MaxPlusSemiring {
+    fn scalar_mul(&self, e: usize) -> Self {
+        if e == 0 {
+            Self::zero()
+        } else {
+            *self
+        }
+    }
+}
+
 impl One for MaxPlusSemiring {
     fn one() -> Self {
         Self(0)
@@ -36,19 +61,53 @@ impl One for MaxPlusSemiring {
 impl Mul for MaxPlusSemiring {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
-        Self(self.0 + rhs.0)
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        if self.0 == i64::MAX || rhs.0 == i64::MAX {
+            return Self(i64::MAX);
+        }
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl MulAssign for MaxPlusSemiring {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Display for MaxPlusSemiring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.value() {
+            Some(i64::MAX) => write!(f, "inf"),
+            Some(v) => write!(f, "{}", v),
+            None => write!(f, "-inf"),
+        }
     }
 }
 
-#[derive(Clone, Copy)]
+// 零元は +∞ (i64::MAX) で, 積 (整数の和) は飽和させて +∞ を吸収元にする
+// i64::MIN は -∞ (負閉路) として扱い, 有限の値を掛けても -∞ のまま (+∞ を掛けたときだけ +∞)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct MinPlusSemiring(i64);
 
+impl MinPlusSemiring {
+    pub fn new(v: i64) -> Self {
+        Self(v)
+    }
+    // +∞ なら None (-∞ は Some(i64::MIN))
+    pub fn value(&self) -> Option<i64> {
+        (!self.is_zero()).then_some(self.0)
+    }
+}
+
 impl Zero for MinPlusSemiring {
     fn zero() -> Self {
-        Self(1 << 60)
+        Self(i64::MAX)
     }
     fn is_zero(&self) -> bool {
-        self.0 >= 1 << 60
+        self.0 == i64::MAX
     }
 }
 
@@ -65,6 +124,17 @@ impl AddAssign for MinPlusSemiring {
     }
 }
 
+// 和は冪等
+impl ScalarMul for MinPlusSemiring {
+    fn scalar_mul(&self, e: usize) -> Self {
+        if e == 0 {
+            Self::zero()
+        } else {
+            *self
+        }
+    }
+}
+
 impl One for MinPlusSemiring {
     fn one() -> Self {
         Self(0)
@@ -74,6 +144,146 @@ impl One for MinPlusSemiring {
 impl Mul for MinPlusSemiring {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
-        Self(self.0 + rhs.0)
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        if self.0 == i64::MIN || rhs.0 == i64::MIN {
+            return Self(i64::MIN);
+        }
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl MulAssign for MinPlusSemiring {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Display for MinPlusSemiring {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.value() {
+            Some(i64::MIN) => write!(f, "-inf"),
+            Some(v) => write!(f, "{}", v),
+            None => write!(f, "inf"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::algebraic::ring::matrix::Matrix;
+    use rand::Rng;
+
+    #[test]
+    fn saturation() {
+        let inf = MinPlusSemiring::zero();
+        let big = MinPlusSemiring::new(i64::MAX - 1);
+        assert_eq!(inf * MinPlusSemiring::new(-5), inf);
+        assert_eq!(big * big, inf);
+        assert_eq!(inf + MinPlusSemiring::new(3), MinPlusSemiring::new(3));
+        assert_eq!(
+            MinPlusSemiring::new(3).scalar_mul(4),
+            MinPlusSemiring::new(3)
+        );
+        let ninf = MaxPlusSemiring::zero();
+        assert_eq!(ninf * MaxPlusSemiring::new(i64::MAX), ninf);
+        assert_eq!(
+            MaxPlusSemiring::new(2) * MaxPlusSemiring::new(-7),
+            MaxPlusSemiring::new(-5)
+        );
+        assert_eq!(format!("{} {}", inf, ninf), "inf -inf");
+        // 飽和した -∞ に有限の値を掛けても有限に戻らない
+        let neg = MinPlusSemiring::new(i64::MIN + 3) * MinPlusSemiring::new(-10);
+        assert_eq!(neg, MinPlusSemiring::new(i64::MIN));
+        assert_eq!(neg * MinPlusSemiring::new(5), neg);
+        assert_eq!(MinPlusSemiring::new(7) * neg, neg);
+        assert_eq!(neg * inf, inf);
+        let pos = MaxPlusSemiring::new(i64::MAX - 3) * MaxPlusSemiring::new(10);
+        assert_eq!(pos, MaxPlusSemiring::new(i64::MAX));
+        assert_eq!(pos * MaxPlusSemiring::new(-5), pos);
+        assert_eq!(pos * ninf, ninf);
+        assert_eq!(format!("{} {}", neg, pos), "-inf inf");
+    }
+
+    #[test]
+    fn paths() {
+        let mut rng = rand::thread_rng();
+        let n = 6;
+        // 辺 (i, j) の重み (None なら辺がない)
+        let weights: Vec<Vec<Option<i64>>> = (0..n)
+            .map(|_| {
+                (0..n)
+                    .map(|_| rng.gen_bool(0.5).then(|| rng.gen_range(-10..10)))
+                    .collect()
+            })
+            .collect();
+        let shortest = Matrix::from(
+            weights
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|w| w.map_or(MinPlusSemiring::zero(), MinPlusSemiring::new))
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>(),
+        );
+        let longest = Matrix::from(
+            weights
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|w| w.map_or(MaxPlusSemiring::zero(), MaxPlusSemiring::new))
+                        .collect()
+                })
+                .collect::<Vec<Vec<_>>>(),
+        );
+        // ちょうど k 本の辺を通る最短路と最長路を愚直な DP と比べる
+        let (mut min_dp, mut max_dp) = (vec![vec![None; n]; n], vec![vec![None; n]; n]);
+        for i in 0..n {
+            min_dp[i][i] = Some(0);
+            max_dp[i][i] = Some(0);
+        }
+        for k in 0..8 {
+            let (a, b) = (shortest.pow(k), longest.pow(k));
+            for i in 0..n {
+                for j in 0..n {
+                    assert_eq!(a[(i, j)].value(), min_dp[i][j]);
+                    assert_eq!(b[(i, j)].value(), max_dp[i][j]);
+                }
+            }
+            let step = |dp: &Vec<Vec<Option<i64>>>, better: fn(i64, i64) -> i64| {
+                let mut next = vec![vec![None; n]; n];
+                for i in 0..n {
+                    for l in 0..n {
+                        for j in 0..n {
+                            if let (Some(x), Some(w)) = (dp[i][l], weights[l][j]) {
+                                let v: Option<i64> = next[i][j];
+                                next[i][j] = Some(v.map_or(x + w, |v| better(v, x + w)));
+                            }
+                        }
+                    }
+                }
+                next
+            };
+            min_dp = step(&min_dp, std::cmp::min);
+            max_dp = step(&max_dp, std::cmp::max);
+        }
+        // 大きな k でも和があふれずに飽和する
+        let cycle = Matrix::from(vec![
+            vec![MinPlusSemiring::zero(), MinPlusSemiring::new(-1 << 40)],
+            vec![MinPlusSemiring::new(-1 << 40), MinPlusSemiring::zero()],
+        ]);
+        let p = cycle.pow(1 << 30);
+        assert_eq!(p[(0, 0)].value(), Some(i64::MIN));
+        assert!(p[(0, 1)].is_zero());
+        // 負閉路を通った後に正の重みの辺を通っても -∞ のまま
+        let q = p * Matrix::from(vec![
+            vec![MinPlusSemiring::new(5), MinPlusSemiring::new(7)],
+            vec![MinPlusSemiring::zero(), MinPlusSemiring::zero()],
+        ]);
+        assert_eq!(q[(0, 0)].value(), Some(i64::MIN));
+        assert_eq!(q[(0, 1)].value(), Some(i64::MIN));
     }
 }
@@ -3,13 +3,13 @@ mod geometry;
 mod graph;
 mod util;
 
-use crate::algebraic::{ring::integer::ZZ, ring::polynomial::FPS};
+use crate::algebraic::{ring::integer::ZZ, ring::polynomial::Polynomial};
 use num::Integer;
 
 fn main() {
     let a = ZZ::from(0);
-    let f = FPS::from(vec![3, 0, 0, 1]) ^ 2;
-    let f: FPS<isize> = FPS::from(vec![0, 3, 0, 5]) + f;
+    let f = Polynomial::from(vec![3, 0, 0, 1]) ^ 2;
+    let f: Polynomial<isize> = Polynomial::from(vec![0, 3, 0, 5]) + f;
     println!("{}", f);
     let a: ZZ = 15.into();
     let b: ZZ = 303.into();